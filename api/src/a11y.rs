@@ -0,0 +1,243 @@
+//! Accessibility properties of the members and snapshots of the tree assistive technologies see.
+//!
+//! The name, description and role of a member can be set explicitly with `Member::set_accessible_*`.
+//! Unset, the name defaults to the `HasLabel` label and the role to the kind of the member.
+
+use crate::controls::Member;
+use crate::ids::Id;
+use crate::types;
+
+use std::fmt::{self, Write};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Role {
+    Window,
+    Dialog,
+    Alert,
+    Menu,
+    Group,
+    Button,
+    Label,
+    Image,
+    ProgressBar,
+    List,
+    Tree,
+    Unknown,
+}
+
+impl Role {
+    /// The default role for the kind of the member.
+    pub fn of(member: &dyn Member) -> Role {
+        if let Some(control) = member.is_control() {
+            if control.is_button().is_some() {
+                Role::Button
+            } else if control.is_text().is_some() {
+                Role::Label
+            } else if control.is_image().is_some() {
+                Role::Image
+            } else if control.is_progress_bar().is_some() {
+                Role::ProgressBar
+            } else if control.is_list().is_some() {
+                Role::List
+            } else if control.is_tree().is_some() {
+                Role::Tree
+            } else if control.is_container().is_some() {
+                Role::Group
+            } else {
+                Role::Unknown
+            }
+        } else if member.is_window().is_some() {
+            Role::Window
+        } else if member.is_tray().is_some() {
+            Role::Menu
+        } else if member.is_message().is_some() {
+            Role::Alert
+        } else if member.is_file_dialog().is_some() {
+            Role::Dialog
+        } else {
+            Role::Unknown
+        }
+    }
+    /// Roles that mean nothing to the user without a name.
+    pub fn requires_name(self) -> bool {
+        matches!(self, Role::Button | Role::Image)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct State {
+    /// Not `Visibility::Visible` itself, regardless of the ancestors.
+    pub hidden: bool,
+    pub clickable: bool,
+    pub progress: Option<types::Progress>,
+}
+
+impl State {
+    pub fn of(member: &dyn Member) -> State {
+        State {
+            hidden: member.is_has_visibility().is_some_and(|v| v.visibility() != types::Visibility::Visible),
+            clickable: member.is_clickable().is_some(),
+            progress: member.is_has_progress().map(|p| p.progress()),
+        }
+    }
+}
+
+/// Snapshot of the accessibility properties of a member and its children, see `snapshot`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct AccessibleNode {
+    pub id: Id,
+    pub role: Role,
+    pub name: Option<String>,
+    pub description: Option<String>,
+    pub state: State,
+    pub children: Vec<AccessibleNode>,
+}
+
+impl AccessibleNode {
+    /// The node and all its descendants, depth first.
+    pub fn nodes(&self) -> Vec<&AccessibleNode> {
+        let mut out = vec![self];
+        for child in self.children.iter() {
+            out.extend(child.nodes());
+        }
+        out
+    }
+    pub fn to_text(&self) -> String {
+        let mut out = String::new();
+        self.write_text(&mut out, 0);
+        out
+    }
+
+    fn write_text(&self, out: &mut String, depth: usize) {
+        for _ in 0..depth {
+            out.push_str("  ");
+        }
+        let _ = write!(out, "{:?} {:?}", self.role, self.id);
+        if let Some(ref name) = self.name {
+            let _ = write!(out, " {:?}", name);
+        }
+        if let Some(ref description) = self.description {
+            let _ = write!(out, " ({:?})", description);
+        }
+        if self.state.hidden {
+            out.push_str(" hidden");
+        }
+        if self.state.clickable {
+            out.push_str(" clickable");
+        }
+        if let Some(ref progress) = self.state.progress {
+            let _ = write!(out, " {:?}", progress);
+        }
+        out.push('\n');
+        for child in self.children.iter() {
+            child.write_text(out, depth + 1);
+        }
+    }
+}
+impl fmt::Display for AccessibleNode {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.to_text())
+    }
+}
+
+/// Snapshots the accessibility properties of the member and all its children.
+pub fn snapshot(member: &dyn Member) -> AccessibleNode {
+    AccessibleNode {
+        id: member.id(),
+        role: member.accessible_role(),
+        name: member.accessible_name().map(|name| name.into_owned()),
+        description: member.accessible_description().map(|description| description.into()),
+        state: member.accessible_state(),
+        children: crate::traversal::children(member).into_iter().map(snapshot).collect(),
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum IssueKind {
+    /// The role requires a name, like an `Image` or an icon-only `Button`, but there is none.
+    MissingName,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Issue {
+    pub id: Id,
+    pub role: Role,
+    pub kind: IssueKind,
+}
+
+impl fmt::Display for Issue {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.kind {
+            IssueKind::MissingName => write!(f, "{:?} {:?} has no accessible name", self.role, self.id),
+        }
+    }
+}
+
+/// What `lint_with` checks.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct LintOptions {
+    /// Also checks the hidden members and their descendants, which assistive technologies skip.
+    pub include_hidden: bool,
+}
+
+/// Finds the accessibility problems of the shown part of the tree, e.g. to fail a CI run on them.
+pub fn lint(node: &AccessibleNode) -> Vec<Issue> {
+    lint_with(node, LintOptions::default())
+}
+pub fn lint_with(node: &AccessibleNode, options: LintOptions) -> Vec<Issue> {
+    let mut issues = Vec::new();
+    lint_node(node, options, &mut issues);
+    issues
+}
+
+fn lint_node(node: &AccessibleNode, options: LintOptions, issues: &mut Vec<Issue>) {
+    if node.state.hidden && !options.include_hidden {
+        return;
+    }
+    if node.role.requires_name() && node.name.as_ref().is_none_or(|name| name.trim().is_empty()) {
+        issues.push(Issue { id: node.id, role: node.role, kind: IssueKind::MissingName });
+    }
+    for child in node.children.iter() {
+        lint_node(child, options, issues);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn node(role: Role, name: Option<&str>, hidden: bool, children: Vec<AccessibleNode>) -> AccessibleNode {
+        AccessibleNode {
+            id: Id::next(),
+            role,
+            name: name.map(String::from),
+            description: None,
+            state: State { hidden, ..Default::default() },
+            children,
+        }
+    }
+
+    #[test]
+    fn lint_reports_unnamed_buttons_and_images() {
+        let named = node(Role::Button, Some("Ok"), false, vec![]);
+        let blank = node(Role::Button, Some("  "), false, vec![]);
+        let image = node(Role::Image, None, false, vec![]);
+        let label = node(Role::Label, None, false, vec![]);
+        let (blank_id, image_id) = (blank.id, image.id);
+        let root = node(Role::Window, None, false, vec![node(Role::Group, None, false, vec![named, blank, image]), label]);
+        let issues = lint(&root);
+        assert_eq!(issues.iter().map(|issue| issue.id).collect::<Vec<_>>(), vec![blank_id, image_id]);
+        assert!(issues.iter().all(|issue| issue.kind == IssueKind::MissingName));
+        assert_eq!(issues[1].role, Role::Image);
+    }
+    #[test]
+    fn lint_skips_hidden_subtrees_unless_asked() {
+        let image = node(Role::Image, None, false, vec![]);
+        let image_id = image.id;
+        let root = node(Role::Window, None, false, vec![node(Role::Group, None, true, vec![image])]);
+        assert!(lint(&root).is_empty());
+        let issues = lint_with(&root, LintOptions { include_hidden: true });
+        assert_eq!(issues.len(), 1);
+        assert_eq!(issues[0].id, image_id);
+    }
+}
@@ -2,15 +2,20 @@ use crate::{controls, types::{adapter}};
 
 pub use crate::inner::{
 	auto::OnFrame,
+    application::{OnStart, OnBeforeExit, OnIdle, OnLastWindowClosed, OnThemeChange},
     has_size::OnSize,
     has_visibility::OnVisibility,
     clickable::OnClick,
     closeable::OnClose, 
     item_clickable::OnItemClick,
-    member::MemberBase,
+    member::{MemberBase, OnEvent},
+    timer::OnTimer,
+    instance::OnInstanceArgs,
+    window::OnScaleChange,
 };
 
 use std::fmt::{Display, Formatter, Result as FmtResult};
+use std::mem;
 use std::sync::atomic::{AtomicUsize, Ordering};
 use std::sync::mpsc::{SendError, Sender};
 
@@ -39,6 +44,96 @@ pub trait Callback {
     fn id(&self) -> CallbackId;
 }
 
+/// Ordered set of subscribers of a single callback kind.
+/// Subscribers are invoked in the order they were added.
+///
+/// Dispatch goes through `take` and `restore`, so a subscriber may add or remove subscribers of the same list
+/// while it runs: the added ones are first called with the next dispatch, the removed ones are dropped on `restore`.
+pub struct CallbackList<T: Callback> {
+    callbacks: Vec<T>,
+    /// Ids of the subscribers taken out by the dispatches in progress that are still subscribed.
+    taken: Vec<CallbackId>,
+}
+impl<T: Callback> CallbackList<T> {
+    pub fn new() -> Self {
+        CallbackList { callbacks: Vec::new(), taken: Vec::new() }
+    }
+    pub fn add(&mut self, callback: T) -> CallbackId {
+        let id = callback.id();
+        self.callbacks.push(callback);
+        id
+    }
+    pub fn remove(&mut self, id: CallbackId) -> bool {
+        if let Some(index) = self.taken.iter().position(|taken| *taken == id) {
+            self.taken.remove(index);
+            return true;
+        }
+        match self.callbacks.iter().position(|cb| cb.id() == id) {
+            Some(index) => {
+                self.callbacks.remove(index);
+                true
+            }
+            None => false,
+        }
+    }
+    /// Replaces all subscribers with the given one, if any.
+    pub fn set(&mut self, callback: Option<T>) {
+        self.clear();
+        if let Some(callback) = callback {
+            self.callbacks.push(callback);
+        }
+    }
+    pub fn clear(&mut self) {
+        self.callbacks.clear();
+        self.taken.clear();
+    }
+    pub fn len(&self) -> usize {
+        self.callbacks.len() + self.taken.len()
+    }
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+    /// Takes the subscribers out for a dispatch. Hand them back with `restore` once all of them were called.
+    pub fn take(&mut self) -> Vec<T> {
+        let callbacks = mem::take(&mut self.callbacks);
+        self.taken.extend(callbacks.iter().map(|cb| cb.id()));
+        callbacks
+    }
+    /// Puts the subscribers taken with `take` back in front of the ones added meanwhile,
+    /// dropping the ones removed meanwhile.
+    pub fn restore(&mut self, callbacks: Vec<T>) {
+        let taken = &mut self.taken;
+        let mut restored: Vec<T> = callbacks
+            .into_iter()
+            .filter(|cb| match taken.iter().position(|id| *id == cb.id()) {
+                Some(index) => {
+                    taken.remove(index);
+                    true
+                }
+                None => false,
+            })
+            .collect();
+        restored.append(&mut self.callbacks);
+        self.callbacks = restored;
+    }
+    pub fn iter(&self) -> ::std::slice::Iter<'_, T> {
+        self.callbacks.iter()
+    }
+    pub fn iter_mut(&mut self) -> ::std::slice::IterMut<'_, T> {
+        self.callbacks.iter_mut()
+    }
+}
+impl<T: Callback> Default for CallbackList<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+impl<T: Callback> ::std::fmt::Debug for CallbackList<T> {
+    fn fmt(&self, f: &mut Formatter) -> FmtResult {
+        f.debug_list().entries(self.callbacks.iter().map(|cb| cb.id())).finish()
+    }
+}
+
 #[derive(Debug, Clone)]
 pub struct AsyncFeeder<T: Callback> {
     sender: Sender<T>,
@@ -112,3 +207,66 @@ macro_rules! callback {
 callback!(Action, FnMut(&mut dyn controls::Member) -> bool);
 
 on!(ItemChange (&mut MemberBase, adapter::Change));
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    struct Counter(CallbackId);
+    impl Callback for Counter {
+        fn name(&self) -> &'static str {
+            "Counter"
+        }
+        fn id(&self) -> CallbackId {
+            self.0
+        }
+    }
+    fn counter() -> Counter {
+        Counter(CallbackId::next())
+    }
+
+    #[test]
+    fn restore_keeps_order_and_adds_new_subscribers_last() {
+        let mut list = CallbackList::new();
+        let first = list.add(counter());
+        let second = list.add(counter());
+        let taken = list.take();
+        assert_eq!(taken.len(), 2);
+        let third = list.add(counter());
+        list.restore(taken);
+        assert_eq!(list.iter().map(|cb| cb.id()).collect::<Vec<_>>(), vec![first, second, third]);
+    }
+    #[test]
+    fn remove_during_dispatch_drops_the_subscriber() {
+        let mut list = CallbackList::new();
+        let first = list.add(counter());
+        let second = list.add(counter());
+        let taken = list.take();
+        assert!(list.remove(first));
+        assert!(!list.remove(first));
+        assert_eq!(list.len(), 1);
+        list.restore(taken);
+        assert_eq!(list.iter().map(|cb| cb.id()).collect::<Vec<_>>(), vec![second]);
+    }
+    #[test]
+    fn clear_during_dispatch_drops_everything() {
+        let mut list = CallbackList::new();
+        list.add(counter());
+        let taken = list.take();
+        list.set(Some(counter()));
+        list.restore(taken);
+        assert_eq!(list.len(), 1);
+    }
+    #[test]
+    fn nested_dispatches_restore_in_order() {
+        let mut list = CallbackList::new();
+        let first = list.add(counter());
+        let outer = list.take();
+        let second = list.add(counter());
+        let inner = list.take();
+        let third = list.add(counter());
+        list.restore(inner);
+        list.restore(outer);
+        assert_eq!(list.iter().map(|cb| cb.id()).collect::<Vec<_>>(), vec![first, second, third]);
+    }
+}
@@ -1,5 +1,7 @@
 pub use crate::inner::{
     has_native_id::HasNativeId,
+    has_color::{HasColor, MaybeHasColor},
+    has_font::{HasFont, MaybeHasFont},
     has_image::{HasImage, MaybeHasImage},
     has_label::{HasLabel, MaybeHasLabel},
     has_layout::{HasLayout, MaybeHasLayout},
@@ -18,6 +20,7 @@ pub use crate::inner::{
     text::{Text, MaybeText, NewText},
     window::{Window, NewWindow},
     message::{Message, NewMessage},
+    file_dialog::{FileDialog, NewFileDialog},
     image::{Image, MaybeImage, NewImage},
     frame::{Frame, MaybeFrame, NewFrame},
     layout_linear::{LinearLayout, MaybeLinearLayout, NewLinearLayout},
@@ -0,0 +1,72 @@
+use std::error::Error as StdError;
+use std::fmt;
+
+/// Errors reported by the fallible (`try_*`) parts of the API.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Error {
+    /// The Application is accessed or created outside of its UI thread.
+    NonUiThread,
+    /// An Application already exists in this thread.
+    AlreadyInitialized,
+    /// The arguments were handed over to the already running instance of a single instance Application.
+    Forwarded,
+    /// A member created by one backend is used with another one.
+    IncompatibleBackend,
+    IndexOutOfBounds(usize, usize),
+    /// A `types::Selector` that could not be parsed.
+    InvalidSelector(String),
+    /// A value that could not be parsed from its textual form, like a `types::Color`.
+    Parse(String),
+    /// A file that could not be read, like a `l10n::Catalog`.
+    Io(String),
+    /// A value was requested from an empty holder, e.g. `VecItemChangeOption::Remove`.
+    Empty,
+    #[cfg(feature = "markup")]
+    Markup(crate::markup::MarkupError),
+    /// Markup that is not valid JSON, or does not fit the expected structure.
+    #[cfg(feature = "markup")]
+    MarkupSyntax(String),
+    Unspecified,
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::NonUiThread => write!(f, "Application requested from non-UI thread"),
+            Error::AlreadyInitialized => write!(f, "Application is already initialized in this thread"),
+            Error::Forwarded => write!(f, "Arguments forwarded to the running instance"),
+            Error::IncompatibleBackend => write!(f, "Attempt to use the control from an incompatible backend"),
+            Error::IndexOutOfBounds(index, len) => write!(f, "Index {} is out of bounds (length {})", index, len),
+            Error::InvalidSelector(e) => write!(f, "Invalid selector: {}", e),
+            Error::Parse(e) => write!(f, "Parse error: {}", e),
+            Error::Io(e) => write!(f, "I/O error: {}", e),
+            Error::Empty => write!(f, "No value present"),
+            #[cfg(feature = "markup")]
+            Error::Markup(e) => write!(f, "Markup error: {}", e),
+            #[cfg(feature = "markup")]
+            Error::MarkupSyntax(e) => write!(f, "Markup syntax error: {}", e),
+            Error::Unspecified => write!(f, "Unspecified error"),
+        }
+    }
+}
+
+impl StdError for Error {}
+
+#[cfg(feature = "markup")]
+impl From<crate::markup::MarkupError> for Error {
+    fn from(e: crate::markup::MarkupError) -> Self {
+        Error::Markup(e)
+    }
+}
+
+#[cfg(all(test, feature = "markup"))]
+mod tests {
+    use super::*;
+    use crate::markup::MarkupError;
+
+    #[test]
+    fn markup_errors_read_as_messages() {
+        assert_eq!(Error::from(MarkupError::CallbackNotFound).to_string(), "Markup error: no callback is registered under this name");
+        assert_eq!(Error::from(MarkupError::IdAlreadyExists).to_string(), "Markup error: an id is registered under this name already");
+    }
+}
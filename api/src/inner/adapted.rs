@@ -73,6 +73,7 @@ pub struct AdapterInnerCallback {
 impl AdapterInnerCallback {
     pub fn on_item_change(&mut self, value: adapter::Change) {
         if !self.target.is_null() {
+            crate::trace::adapter_change(unsafe { &*self.target }, &value);
             (self.on_item_change.as_mut())(unsafe {&mut *self.target}, value)
         }
     }
@@ -5,12 +5,23 @@ use super::closeable::Closeable;
 use super::seal::Sealed;
 use super::window::{NewWindow, Window};
 use super::tray::{NewTray, Tray};
+use super::timer::{OnTimer, SystemClock, Timers};
+use super::executor::LocalExecutor;
+use super::instance::{InstanceRole, OnInstanceArgs, SingleInstance};
 
-use crate::{callbacks, types, ids};
+use crate::{callbacks, l10n, style, types, ids};
+use crate::callbacks::{Callback, CallbackId, CallbackList};
 
 use std::any::Any;
 use std::borrow::Cow;
 use std::sync::mpsc;
+use std::time::Duration;
+
+on!(Start (&mut dyn Application));
+on!(BeforeExit (&mut dyn Application) -> bool);
+on!(Idle (&mut dyn Application));
+on!(LastWindowClosed (&mut dyn Application));
+on!(ThemeChange (&mut dyn Application, types::ColorScheme));
 
 define! {
     Application: HasNativeId {
@@ -19,6 +30,22 @@ define! {
             pub trays: Vec<Box<dyn Tray>>,
             queue: mpsc::Receiver<OnFrame>,
             sender: mpsc::Sender<OnFrame>,
+            /// Queued `on_frame` callbacks that `remove_callback` can find, see `run_frames`.
+            frames: Vec<CallbackId>,
+            removed_frames: Vec<CallbackId>,
+            pub timers: Timers,
+            pub executor: LocalExecutor,
+            pub quit_policy: types::QuitPolicy,
+            on_start: CallbackList<OnStart>,
+            on_before_exit: CallbackList<OnBeforeExit>,
+            on_idle: CallbackList<OnIdle>,
+            on_last_window_closed: CallbackList<OnLastWindowClosed>,
+            pub instance: Option<SingleInstance>,
+            on_instance_args: CallbackList<OnInstanceArgs>,
+            stylesheet: style::Stylesheet,
+            color_scheme: types::ColorScheme,
+            on_theme_change: CallbackList<OnThemeChange>,
+            localizer: l10n::Localizer,
         },
         extends: {
             AsAny + Sealed
@@ -29,7 +56,7 @@ define! {
             fn find_member_mut<'a>(&'a mut self, arg: types::FindBy<'a>) -> Option<&'a mut dyn Member>;
             fn find_member<'a>(&'a self, arg: types::FindBy<'a>) -> Option<&'a dyn Member>;
             fn exit(self: Box<Self>);
-            fn prepare_exit(&mut self);
+            fn prepare_exit(&mut self) -> bool;
             fn on_frame(&mut self, cb: OnFrame);
             fn on_frame_async_feeder(&mut self) -> callbacks::AsyncFeeder<OnFrame>;
             
@@ -39,8 +66,77 @@ define! {
             fn frame_sleep(&self) -> u32;
             fn set_frame_sleep(&mut self, value: u32);
             
+            fn add_timer(&mut self, interval: Duration, repeat: bool, cb: OnTimer) -> callbacks::CallbackId;
+            fn remove_timer(&mut self, id: callbacks::CallbackId) -> bool;
+            
+            fn spawn_local(&mut self, future: types::LocalFuture) -> callbacks::CallbackId;
+            fn cancel_local(&mut self, id: callbacks::CallbackId) -> bool;
+            
+            fn on_start(&mut self, cb: Option<OnStart>);
+            fn add_on_start(&mut self, cb: OnStart) -> callbacks::CallbackId;
+            fn on_before_exit(&mut self, cb: Option<OnBeforeExit>);
+            fn add_on_before_exit(&mut self, cb: OnBeforeExit) -> callbacks::CallbackId;
+            fn on_idle(&mut self, cb: Option<OnIdle>);
+            fn add_on_idle(&mut self, cb: OnIdle) -> callbacks::CallbackId;
+            fn on_last_window_closed(&mut self, cb: Option<OnLastWindowClosed>);
+            fn add_on_last_window_closed(&mut self, cb: OnLastWindowClosed) -> callbacks::CallbackId;
+            fn on_instance_args(&mut self, cb: Option<OnInstanceArgs>);
+            fn add_on_instance_args(&mut self, cb: OnInstanceArgs) -> callbacks::CallbackId;
+            fn on_theme_change(&mut self, cb: Option<OnThemeChange>);
+            fn add_on_theme_change(&mut self, cb: OnThemeChange) -> callbacks::CallbackId;
+            /// Removes a callback of the application, a timer or a queued `on_frame` callback.
+            /// Frame callbacks fed from other threads can be removed once they ran.
+            fn remove_callback(&mut self, id: callbacks::CallbackId) -> bool;
+            
+            fn quit_policy(&self) -> types::QuitPolicy;
+            fn set_quit_policy(&mut self, policy: types::QuitPolicy);
+            
+            /// The current color scheme of the system, the stylesheet follows it.
+            fn color_scheme(&self) -> types::ColorScheme;
+            
+            fn stylesheet(&self) -> &style::Stylesheet;
+            /// Replaces the stylesheet, switched to the current color scheme, and restyles all windows and trays.
+            fn set_stylesheet(&mut self, sheet: style::Stylesheet);
+            /// Resolves the styles of all members again, e.g. after changing their classes or tags.
+            fn restyle(&mut self);
+            
+            fn localizer(&self) -> &l10n::Localizer;
+            /// For loading the catalogs. Switching the locale here does not relabel the members, see `set_locale`.
+            fn localizer_mut(&mut self) -> &mut l10n::Localizer;
+            /// Switches the locale and translates the labels of all members set with `HasLabel::set_label_key` again.
+            fn set_locale(&mut self, locale: &str);
+            
+            fn clipboard(&self) -> &dyn types::Clipboard;
+            fn clipboard_mut(&mut self) -> &mut dyn types::Clipboard;
+            
             fn roots<'a>(&'a self) -> Box<dyn Iterator<Item = &'a (dyn Member)> + 'a>; //E0562 :(
             fn roots_mut<'a>(&'a mut self) -> Box<dyn Iterator<Item = &'a mut (dyn Member)> + 'a>; //E0562 :(
+            
+            /// Snapshots the control trees of all windows and trays, see `utils::dump_layout`.
+            fn dump_layout(&self) -> Vec<crate::layout::LayoutDump> {
+                self.roots().map(crate::utils::dump_layout).collect()
+            }
+            /// Snapshots the accessibility trees of all windows and trays, see `a11y::snapshot`.
+            fn accessibility_tree(&self) -> Vec<crate::a11y::AccessibleNode> {
+                self.roots().map(crate::a11y::snapshot).collect()
+            }
+            /// Walks all members of all windows and trays, see `traversal`.
+            fn depth_first<'a>(&'a self) -> crate::traversal::DepthFirst<'a> {
+                crate::traversal::DepthFirst::from_roots(self.roots())
+            }
+            fn depth_first_mut<'a>(&'a mut self) -> crate::traversal::DepthFirstMut<'a> {
+                crate::traversal::DepthFirstMut::from_roots(self.roots_mut())
+            }
+            fn breadth_first<'a>(&'a self) -> crate::traversal::BreadthFirst<'a> {
+                crate::traversal::BreadthFirst::from_roots(self.roots())
+            }
+            fn breadth_first_mut<'a>(&'a mut self) -> crate::traversal::BreadthFirstMut<'a> {
+                crate::traversal::BreadthFirstMut::from_roots(self.roots_mut())
+            }
+            /// All members matching `arg`, in depth-first order, unlike `find_member` that stops at the first one.
+            fn find_members<'a>(&'a self, arg: types::FindBy<'a>) -> Vec<&'a dyn Member> {
+                self.depth_first().map(|node| node.member).filter(|member| arg.matches(*member)).collect()
+            }
         },
         inner: {
             fn add_root(&mut self, m: Box<dyn Closeable>) -> &mut dyn Member;
@@ -62,10 +158,11 @@ define! {
             }
             fn frame_sleep(&self) -> u32;
             fn set_frame_sleep(&mut self, value: u32);
+            fn clipboard(&self) -> &dyn types::Clipboard;
+            fn clipboard_mut(&mut self) -> &mut dyn types::Clipboard;
             fn roots<'a>(&'a self) -> Box<dyn Iterator<Item = &'a (dyn Member)> + 'a>;
             fn roots_mut<'a>(&'a mut self) -> Box<dyn Iterator<Item = &'a mut (dyn Member)> + 'a>;
-        }
-        constructor: {
+            
             fn with_name<S: AsRef<str>>(name: S) -> Box<dyn Application>;
         }
         inner_constructor_params: {
@@ -74,6 +171,27 @@ define! {
     }
 }
 
+pub trait NewApplication {
+    /// Creates the Application for the current thread, which becomes the UI thread.
+    /// Only one Application may exist at a time; once it is dropped, a new one can be created.
+    fn try_with_name<S: AsRef<str>>(name: S) -> types::ApplicationResult;
+    /// Panics where `try_with_name` fails, use that one to handle the `Error`.
+    fn with_name<S: AsRef<str>>(name: S) -> Box<dyn Application> {
+        match Self::try_with_name(name).into_result() {
+            Ok(app) => app,
+            Err(e) => panic!("{}", e),
+        }
+    }
+    /// Same as `try_with_name`, but if another instance with the same name already runs,
+    /// the command line arguments are forwarded to its `on_instance_args` and `ApplicationResult::Forwarded` is returned.
+    fn try_with_name_single_instance<S: AsRef<str>>(name: S) -> types::ApplicationResult;
+    /// Fails with `Error::Forwarded` once the arguments are forwarded to the running instance,
+    /// the caller usually exits then.
+    fn with_name_single_instance<S: AsRef<str>>(name: S) -> Result<Box<dyn Application>, crate::Error> {
+        Self::try_with_name_single_instance(name).into_result()
+    }
+}
+
 pub trait CloseableSpawner {
     fn new_window<W: NewWindow>(&mut self, title: &str, size: types::WindowStartSize, menu: types::Menu) -> ids::Id;
     fn new_tray<T: NewTray>(&mut self, title: &str, icon: image::DynamicImage, menu: types::Menu) -> ids::Id;
@@ -91,11 +209,153 @@ impl<T: ApplicationInner> AApplication<T> {
     pub fn with_inner(inner: T) -> Self {
         let (tx, rx) = mpsc::channel();
         AApplication {
-            base: ApplicationBase { windows: Vec::with_capacity(1), trays: Vec::with_capacity(0), sender: tx, queue: rx },
+            base: ApplicationBase { windows: Vec::with_capacity(1), trays: Vec::with_capacity(0), sender: tx, queue: rx, frames: Vec::new(), removed_frames: Vec::new(), timers: Timers::with_clock(Box::new(SystemClock)), executor: LocalExecutor::new(),
+                quit_policy: Default::default(),
+                on_start: CallbackList::new(),
+                on_before_exit: CallbackList::new(),
+                on_idle: CallbackList::new(),
+                on_last_window_closed: CallbackList::new(),
+                instance: None,
+                on_instance_args: CallbackList::new(),
+                stylesheet: style::Stylesheet::new(),
+                color_scheme: Default::default(),
+                on_theme_change: CallbackList::new(),
+                localizer: l10n::Localizer::new(),
+            },
             inner: inner,
         }
     }
 }
+impl<T: ApplicationInner> AApplication<T> {
+    /// Runs up to `max` queued `on_frame` callbacks and queues the ones that return `true` again for the next frame,
+    /// skipping the ones removed with `remove_callback`. Returns how many ran. Backends call this from their frame loop.
+    pub fn run_frames(&mut self, max: usize) -> usize {
+        let mut ran = 0;
+        let mut again = Vec::new();
+        while ran < max {
+            let mut cb = match self.base.queue.try_recv() {
+                Ok(cb) => cb,
+                Err(_) => break,
+            };
+            let id = cb.id();
+            if !self.base.take_removed_frame(id) {
+                if !self.base.frames.contains(&id) {
+                    // fed from another thread, removable from now on
+                    self.base.frames.push(id);
+                }
+                crate::trace::callback(None, &cb);
+                let repeat = (cb.as_mut())(self);
+                ran += 1;
+                if repeat && !self.base.take_removed_frame(id) {
+                    again.push(cb);
+                    continue;
+                }
+            }
+            self.base.frames.retain(|frame| *frame != id);
+        }
+        for cb in again {
+            let _ = self.base.sender.send(cb);
+        }
+        ran
+    }
+    /// Fires the due timers. Backends call this from their frame loop.
+    pub fn run_timers(&mut self) {
+        let timers = &mut self.base.timers as *mut Timers;
+        Timers::run(timers, self);
+    }
+    /// Polls the woken futures spawned with `spawn_local`. Backends call this from their frame loop.
+    pub fn run_local(&mut self) {
+        LocalExecutor::run(&mut self.base.executor);
+    }
+    /// The topmost controls marked dirty since the last `run_layout`, see `OuterDrawable::mark_dirty`.
+    pub fn queued_layout(&self) -> Vec<ids::Id> {
+        crate::runtime::layout_queue()
+    }
+    /// Relays out the controls marked dirty since the last pass, each of them once.
+    /// Backends call this from their frame loop.
+    pub fn run_layout(&mut self) {
+        for id in crate::runtime::take_layout_queue() {
+            if let Some(control) = Application::find_member_mut(self, types::FindBy::Id(id)).and_then(|member| member.is_control_mut()) {
+                control.invalidate();
+            }
+        }
+    }
+    /// Bubbles an event from the member with the given id up to its root, see `utils::dispatch_event`.
+    /// Backends call this with the key and pointer input the toolkit delivers, and from the native click handlers
+    /// after their `on_click` subscribers ran. Returns `true` if a handler stopped the propagation.
+    pub fn dispatch_event(&mut self, target: ids::Id, event: types::Event) -> bool {
+        match Application::find_member_mut(self, types::FindBy::Id(target)) {
+            Some(member) => crate::utils::dispatch_event(member, event),
+            None => false,
+        }
+    }
+    /// Fires `on_instance_args` for the arguments forwarded by the later launches. Backends call this from their frame loop.
+    pub fn run_instance_args(&mut self) {
+        while let Some(args) = self.base.instance.as_ref().and_then(|instance| instance.try_recv()) {
+            let mut callbacks = self.base.on_instance_args.take();
+            for cb in callbacks.iter_mut() {
+                crate::trace::callback(None, cb);
+                (cb.as_mut())(self, args.as_slice());
+            }
+            self.base.on_instance_args.restore(callbacks);
+        }
+    }
+    /// Switches the stylesheet to the new color scheme, restyles everything and fires `on_theme_change`.
+    /// Backends call this with the scheme of the system on start and whenever it changes.
+    pub fn set_color_scheme(&mut self, scheme: types::ColorScheme) {
+        if self.base.color_scheme == scheme {
+            return;
+        }
+        self.base.color_scheme = scheme;
+        self.base.stylesheet.set_scheme(scheme);
+        Application::restyle(self);
+        let mut callbacks = self.base.on_theme_change.take();
+        for cb in callbacks.iter_mut() {
+            crate::trace::callback(None, cb);
+            (cb.as_mut())(self, scheme);
+        }
+        self.base.on_theme_change.restore(callbacks);
+    }
+    /// Fires `on_idle`. Backends call this once per frame that had no queued work to process.
+    pub fn call_on_idle(&mut self) {
+        let mut callbacks = self.base.on_idle.take();
+        for cb in callbacks.iter_mut() {
+            crate::trace::callback(None, cb);
+            (cb.as_mut())(self);
+        }
+        self.base.on_idle.restore(callbacks);
+    }
+    fn call_on_start(&mut self) {
+        let mut callbacks = self.base.on_start.take();
+        for cb in callbacks.iter_mut() {
+            crate::trace::callback(None, cb);
+            (cb.as_mut())(self);
+        }
+        self.base.on_start.restore(callbacks);
+    }
+    fn call_on_last_window_closed(&mut self) {
+        let mut callbacks = self.base.on_last_window_closed.take();
+        for cb in callbacks.iter_mut() {
+            crate::trace::callback(None, cb);
+            (cb.as_mut())(self);
+        }
+        self.base.on_last_window_closed.restore(callbacks);
+    }
+    /// Returns `false` as soon as one of the `on_before_exit` callbacks vetoes the exit.
+    fn call_on_before_exit(&mut self) -> bool {
+        let mut callbacks = self.base.on_before_exit.take();
+        let mut exit = true;
+        for cb in callbacks.iter_mut() {
+            crate::trace::callback(None, cb);
+            if !(cb.as_mut())(self) {
+                exit = false;
+                break;
+            }
+        }
+        self.base.on_before_exit.restore(callbacks);
+        exit
+    }
+}
 impl ApplicationBase {
     pub fn sender(&mut self) -> &mut mpsc::Sender<OnFrame> {
         &mut self.sender
@@ -103,6 +363,23 @@ impl ApplicationBase {
     pub fn queue(&mut self) -> &mut mpsc::Receiver<OnFrame> {
         &mut self.queue
     }
+    fn remove_frame(&mut self, id: CallbackId) -> bool {
+        if self.frames.contains(&id) && !self.removed_frames.contains(&id) {
+            self.removed_frames.push(id);
+            true
+        } else {
+            false
+        }
+    }
+    fn take_removed_frame(&mut self, id: CallbackId) -> bool {
+        match self.removed_frames.iter().position(|removed| *removed == id) {
+            Some(index) => {
+                self.removed_frames.remove(index);
+                true
+            }
+            None => false,
+        }
+    }
     pub fn roots<'a>(&'a self) -> Box<dyn Iterator<Item = &'a (dyn Member)> + 'a> {
         Box::new(MemberIterator {
             inner: self,
@@ -135,6 +412,7 @@ impl<T: ApplicationInner> Application for AApplication<T> {
     }
     #[inline]
     fn start(&mut self) {
+        self.call_on_start();
         self.inner_mut().start()
     }
     #[inline]
@@ -145,13 +423,18 @@ impl<T: ApplicationInner> Application for AApplication<T> {
     fn find_member<'a>(&'a self, arg: types::FindBy<'a>) -> Option<&'a dyn Member> {
         self.inner().find_member(arg)
     }
+    /// Exits unconditionally, `on_before_exit` cannot veto it.
     #[inline]
     fn exit(mut self: Box<Self>) {
-        self.prepare_exit();
+        self.inner_mut().exit();
     }
     #[inline]
-    fn prepare_exit(&mut self) {
+    fn prepare_exit(&mut self) -> bool {
+        if !self.call_on_before_exit() {
+            return false;
+        }
         self.inner_mut().exit();
+        true
     }
     #[inline]
     fn on_frame_async_feeder(&mut self) -> callbacks::AsyncFeeder<OnFrame> {
@@ -160,6 +443,7 @@ impl<T: ApplicationInner> Application for AApplication<T> {
     }
     #[inline]
     fn on_frame(&mut self, cb: OnFrame) {
+        self.base.frames.push(cb.id());
         let mut feeder = self.base.sender().clone().into();
         self.inner_mut().on_frame(&mut feeder, cb)
     }
@@ -172,6 +456,141 @@ impl<T: ApplicationInner> Application for AApplication<T> {
         self.inner_mut().set_frame_sleep(value)
     }
     #[inline]
+    fn add_timer(&mut self, interval: Duration, repeat: bool, cb: OnTimer) -> callbacks::CallbackId {
+        self.base.timers.add(interval, repeat, cb)
+    }
+    #[inline]
+    fn remove_timer(&mut self, id: callbacks::CallbackId) -> bool {
+        self.base.timers.remove(id)
+    }
+    #[inline]
+    fn spawn_local(&mut self, future: types::LocalFuture) -> callbacks::CallbackId {
+        self.base.executor.spawn(future)
+    }
+    #[inline]
+    fn cancel_local(&mut self, id: callbacks::CallbackId) -> bool {
+        self.base.executor.cancel(id)
+    }
+    #[inline]
+    fn on_start(&mut self, cb: Option<OnStart>) {
+        self.base.on_start.set(cb)
+    }
+    #[inline]
+    fn add_on_start(&mut self, cb: OnStart) -> callbacks::CallbackId {
+        self.base.on_start.add(cb)
+    }
+    #[inline]
+    fn on_before_exit(&mut self, cb: Option<OnBeforeExit>) {
+        self.base.on_before_exit.set(cb)
+    }
+    #[inline]
+    fn add_on_before_exit(&mut self, cb: OnBeforeExit) -> callbacks::CallbackId {
+        self.base.on_before_exit.add(cb)
+    }
+    #[inline]
+    fn on_idle(&mut self, cb: Option<OnIdle>) {
+        self.base.on_idle.set(cb)
+    }
+    #[inline]
+    fn add_on_idle(&mut self, cb: OnIdle) -> callbacks::CallbackId {
+        self.base.on_idle.add(cb)
+    }
+    #[inline]
+    fn on_last_window_closed(&mut self, cb: Option<OnLastWindowClosed>) {
+        self.base.on_last_window_closed.set(cb)
+    }
+    #[inline]
+    fn add_on_last_window_closed(&mut self, cb: OnLastWindowClosed) -> callbacks::CallbackId {
+        self.base.on_last_window_closed.add(cb)
+    }
+    #[inline]
+    fn on_instance_args(&mut self, cb: Option<OnInstanceArgs>) {
+        self.base.on_instance_args.set(cb)
+    }
+    #[inline]
+    fn add_on_instance_args(&mut self, cb: OnInstanceArgs) -> callbacks::CallbackId {
+        self.base.on_instance_args.add(cb)
+    }
+    #[inline]
+    fn on_theme_change(&mut self, cb: Option<OnThemeChange>) {
+        self.base.on_theme_change.set(cb)
+    }
+    #[inline]
+    fn add_on_theme_change(&mut self, cb: OnThemeChange) -> callbacks::CallbackId {
+        self.base.on_theme_change.add(cb)
+    }
+    fn remove_callback(&mut self, id: callbacks::CallbackId) -> bool {
+        self.base.on_instance_args.remove(id)
+            || self.base.on_theme_change.remove(id)
+            || self.base.on_start.remove(id)
+            || self.base.on_before_exit.remove(id)
+            || self.base.on_idle.remove(id)
+            || self.base.on_last_window_closed.remove(id)
+            || self.base.timers.remove(id)
+            || self.base.remove_frame(id)
+    }
+    #[inline]
+    fn quit_policy(&self) -> types::QuitPolicy {
+        self.base.quit_policy
+    }
+    #[inline]
+    fn set_quit_policy(&mut self, policy: types::QuitPolicy) {
+        self.base.quit_policy = policy;
+    }
+    #[inline]
+    fn color_scheme(&self) -> types::ColorScheme {
+        self.base.color_scheme
+    }
+    #[inline]
+    fn stylesheet(&self) -> &style::Stylesheet {
+        &self.base.stylesheet
+    }
+    #[inline]
+    fn set_stylesheet(&mut self, mut sheet: style::Stylesheet) {
+        sheet.set_scheme(self.base.color_scheme);
+        self.base.stylesheet = sheet;
+        Application::restyle(self);
+    }
+    fn restyle(&mut self) {
+        let sheet = self.base.stylesheet.clone();
+        for root in Application::roots_mut(self) {
+            style::restyle(&sheet, root);
+        }
+    }
+    #[inline]
+    fn localizer(&self) -> &l10n::Localizer {
+        &self.base.localizer
+    }
+    #[inline]
+    fn localizer_mut(&mut self) -> &mut l10n::Localizer {
+        &mut self.base.localizer
+    }
+    fn set_locale(&mut self, locale: &str) {
+        self.base.localizer.set_locale(Some(locale));
+        // out of the application while the members are relabeled
+        let localizer = ::std::mem::take(&mut self.base.localizer);
+        let mut walk = Application::depth_first_mut(self);
+        while let Some(node) = walk.next() {
+            let key = match node.member.as_base().label_key() {
+                Some(key) => key.clone(),
+                None => continue,
+            };
+            if let Some(has_label) = node.member.is_has_label_mut() {
+                has_label.set_label(key.format(&localizer).into());
+                has_label.as_base_mut().set_label_key(Some(key));
+            }
+        }
+        self.base.localizer = localizer;
+    }
+    #[inline]
+    fn clipboard(&self) -> &dyn types::Clipboard {
+        self.inner().clipboard()
+    }
+    #[inline]
+    fn clipboard_mut(&mut self) -> &mut dyn types::Clipboard {
+        self.inner_mut().clipboard_mut()
+    }
+    #[inline]
     fn roots<'a>(&'a self) -> Box<dyn Iterator<Item = &'a (dyn Member)> + 'a> {
         self.inner().roots()
     }
@@ -181,11 +600,29 @@ impl<T: ApplicationInner> Application for AApplication<T> {
     }
     #[inline]
     fn add_root(&mut self, m: Box<dyn Closeable>) -> &mut dyn Member {
-        self.inner_mut().add_root(m)
+        let sheet = self.base.stylesheet.clone();
+        let root = self.inner_mut().add_root(m);
+        style::restyle(&sheet, root);
+        root
     }
-    #[inline]
     fn close_root(&mut self, arg: types::FindBy, skip_callbacks: bool) -> bool {
-        self.inner_mut().close_root(arg, skip_callbacks)
+        let had_windows = !self.base.windows.is_empty();
+        if !self.inner_mut().close_root(arg, skip_callbacks) {
+            return false;
+        }
+        let last_window = had_windows && self.base.windows.is_empty();
+        if last_window {
+            self.call_on_last_window_closed();
+        }
+        let quit = match self.base.quit_policy {
+            types::QuitPolicy::OnLastRootClosed => self.base.windows.is_empty() && self.base.trays.is_empty(),
+            types::QuitPolicy::OnLastWindowClosed => last_window,
+            types::QuitPolicy::Explicit => false,
+        };
+        if quit {
+            self.prepare_exit();
+        }
+        true
     }
     #[inline]
     fn as_application(&self) -> &(dyn Application + 'static) { self }
@@ -246,6 +683,12 @@ impl<II: ApplicationInner, T: HasInner<I = II> + Abstract + 'static> Application
     fn set_frame_sleep(&mut self, value: u32) {
         self.inner_mut().set_frame_sleep(value)
     }
+    fn clipboard(&self) -> &dyn types::Clipboard {
+        self.inner().clipboard()
+    }
+    fn clipboard_mut(&mut self) -> &mut dyn types::Clipboard {
+        self.inner_mut().clipboard_mut()
+    }
     fn roots<'a>(&'a self) -> Box<dyn Iterator<Item = &'a (dyn Member)> + 'a> {
         self.inner().roots()
     }
@@ -255,10 +698,31 @@ impl<II: ApplicationInner, T: HasInner<I = II> + Abstract + 'static> Application
 }
 impl<T: ApplicationInner> NewApplication for AApplication<T> {
     #[inline]
-    fn with_name<S: AsRef<str>>(name: S) -> Box<dyn Application> {
+    fn try_with_name<S: AsRef<str>>(name: S) -> types::ApplicationResult {
+        let reservation = match crate::runtime::reserve() {
+            Ok(reservation) => reservation,
+            Err(e) => return e,
+        };
         let mut a = T::with_name(name);
-        crate::runtime::init(a.as_any_mut().downcast_mut::<Self>().unwrap());
-        a
+        reservation.init(a.as_any_mut().downcast_mut::<Self>().unwrap());
+        types::ApplicationResult::New(a)
+    }
+    fn try_with_name_single_instance<S: AsRef<str>>(name: S) -> types::ApplicationResult {
+        let name = name.as_ref();
+        let args: Vec<String> = ::std::env::args().skip(1).collect();
+        // the later launches forward their arguments without bringing up a toolkit
+        let mut instance = match SingleInstance::acquire(name, args.as_slice()) {
+            Ok(InstanceRole::Primary(instance)) => instance,
+            Ok(InstanceRole::Secondary) => return types::ApplicationResult::Forwarded,
+            Err(_) => return types::ApplicationResult::ErrorUnspecified,
+        };
+        let mut a = match Self::try_with_name(name) {
+            types::ApplicationResult::New(a) => a,
+            other => return other,
+        };
+        instance.listen(a.on_frame_async_feeder());
+        a.as_any_mut().downcast_mut::<Self>().unwrap().base.instance = Some(instance);
+        types::ApplicationResult::New(a)
     }
 }
 impl<'a> CloseableSpawner for &'a mut dyn Application {
@@ -292,19 +756,20 @@ impl<'a> Iterator for MemberIterator<'a> {
     type Item = &'a (dyn Member);
 
     fn next(&mut self) -> Option<Self::Item> {
-        if self.index >= self.inner.windows.len() {
+        // the windows first, then the trays
+        if !self.is_tray {
+            if self.needs_window && self.index < self.inner.windows.len() {
+                self.index += 1;
+                return Some(self.inner.windows[self.index - 1].as_member());
+            }
             self.is_tray = true;
             self.index = 0;
         }
-        let ret = if self.needs_tray && self.is_tray {
-            self.inner.trays.get(self.index).map(|tray| tray.as_member())
-        } else if self.needs_window {
-            self.inner.windows.get(self.index).map(|window| window.as_member())
-        } else {
+        if !self.needs_tray || self.index >= self.inner.trays.len() {
             return None;
-        };
+        }
         self.index += 1;
-        ret
+        Some(self.inner.trays[self.index - 1].as_member())
     }
 }
 
@@ -319,19 +784,20 @@ impl<'a> Iterator for MemberIteratorMut<'a> {
     type Item = &'a mut (dyn Member);
 
     fn next(&mut self) -> Option<Self::Item> {
-        if self.needs_tray && self.index >= self.inner.windows.len() {
+        // the windows first, then the trays
+        if !self.is_tray {
+            if self.needs_window && self.index < self.inner.windows.len() {
+                self.index += 1;
+                return bck_is_immensely_stupid(self.inner.windows.get_mut(self.index - 1).map(|window| window.as_member_mut()));
+            }
             self.is_tray = true;
             self.index = 0;
         }
-        let ret = if self.needs_tray && self.is_tray {
-            bck_is_immensely_stupid(self.inner.trays.get_mut(self.index).map(|tray| tray.as_member_mut()))
-        } else if self.needs_window {
-            bck_is_immensely_stupid(self.inner.windows.get_mut(self.index).map(|window| window.as_member_mut()))
-        } else {
+        if !self.needs_tray || self.index >= self.inner.trays.len() {
             return None;
-        };
+        }
         self.index += 1;
-        ret
+        bck_is_immensely_stupid(self.inner.trays.get_mut(self.index - 1).map(|tray| tray.as_member_mut()))
     }
 }
 
@@ -1,11 +1,13 @@
 use super::auto::{HasInner, Spawnable, Abstract};
 use super::clickable::{Clickable, ClickableInner};
 use super::control::{AControl, Control, ControlInner};
+use super::has_color::{HasColor, HasColorInner};
+use super::has_font::{HasFont, HasFontInner};
 use super::has_label::{HasLabel, HasLabelInner};
 use super::member::{AMember, Member};
 
 define! {
-    Button: Control + Clickable + HasLabel {
+    Button: Control + Clickable + HasLabel + HasFont + HasColor {
         constructor: {
             fn with_label<S: AsRef<str>>(label: S) -> Box<dyn Button>;
         }
@@ -1,4 +1,5 @@
 use crate::callbacks::*;
+use crate::{types, utils};
 
 use super::auto::{AsAny, HasInner, Abstract};
 use super::member::{AMember, Member, MemberInner};
@@ -11,8 +12,19 @@ impl<T: ClickableInner + MemberInner> Clickable for AMember<T> {
         self.inner.on_click(cb)
     }
     #[inline]
+    fn add_on_click(&mut self, cb: OnClick) -> CallbackId {
+        self.inner.add_on_click(cb)
+    }
+    #[inline]
+    fn remove_on_click(&mut self, id: CallbackId) -> bool {
+        self.inner.remove_on_click(id)
+    }
+    #[inline]
     fn click(&mut self, skip_callbacks: bool) {
-        self.inner.click(skip_callbacks)
+        self.inner.click(skip_callbacks);
+        if !skip_callbacks {
+            utils::dispatch_event(self, types::Event::Click);
+        }
     }
 
     #[inline]
@@ -36,4 +48,10 @@ impl<II: ClickableInner, T: HasInner<I = II> + Abstract + 'static> ClickableInne
     fn on_click(&mut self, callback: Option<OnClick>) {
         self.inner_mut().on_click(callback)
     }
+    fn add_on_click(&mut self, callback: OnClick) -> CallbackId {
+        self.inner_mut().add_on_click(callback)
+    }
+    fn remove_on_click(&mut self, id: CallbackId) -> bool {
+        self.inner_mut().remove_on_click(id)
+    }
 }
@@ -0,0 +1,21 @@
+use crate::types;
+
+use super::auto::AsAny;
+
+use std::borrow::Cow;
+
+pub trait Clipboard: AsAny + 'static {
+    fn formats(&self) -> Vec<types::ClipboardFormat>;
+
+    fn text(&self) -> Option<String>;
+    fn set_text(&mut self, text: Cow<'_, str>);
+
+    fn image(&self) -> Option<image::DynamicImage>;
+    fn set_image(&mut self, image: Cow<'_, image::DynamicImage>);
+
+    fn clear(&mut self);
+
+    fn has_format(&self, format: types::ClipboardFormat) -> bool {
+        self.formats().contains(&format)
+    }
+}
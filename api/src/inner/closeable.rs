@@ -11,12 +11,16 @@ define_abstract! {
         },
         outer: {
             fn on_close(& mut self, callback : Option < OnClose >) ; 
+            fn add_on_close(&mut self, callback: OnClose) -> CallbackId;
+            fn remove_on_close(&mut self, id: CallbackId) -> bool;
             fn application(&self) -> &dyn Application;
             fn application_mut(&mut self) -> &mut dyn Application;
         },
         inner: {
             fn close (& mut self, skip_callbacks : bool) -> bool ; 
             fn on_close(& mut self, callback : Option < OnClose >) ;
+            fn add_on_close(&mut self, callback: OnClose) -> CallbackId;
+            fn remove_on_close(&mut self, id: CallbackId) -> bool;
             fn application<'a>(&'a self, base: &'a MemberBase) -> &'a dyn Application;
             fn application_mut<'a>(&'a mut self, base: &'a mut MemberBase) -> &'a mut dyn Application;
         }
@@ -61,6 +65,12 @@ impl<II: CloseableInner, T: HasInner<I = II> + Abstract + 'static> CloseableInne
     fn on_close(&mut self, callback: Option<OnClose>) {
         self.inner_mut().on_close(callback)
     }
+    fn add_on_close(&mut self, callback: OnClose) -> CallbackId {
+        self.inner_mut().add_on_close(callback)
+    }
+    fn remove_on_close(&mut self, id: CallbackId) -> bool {
+        self.inner_mut().remove_on_close(id)
+    }
     fn application<'a>(&'a self, base: &'a MemberBase) -> &'a dyn Application {
         self.inner().application(base)
     }
@@ -73,6 +83,12 @@ impl<T: CloseableInner> Closeable for AMember<T> {
     fn on_close(&mut self, callback: Option<OnClose>) {
         self.inner.on_close(callback)
     }
+    fn add_on_close(&mut self, callback: OnClose) -> CallbackId {
+        self.inner.add_on_close(callback)
+    }
+    fn remove_on_close(&mut self, id: CallbackId) -> bool {
+        self.inner.remove_on_close(id)
+    }
     fn as_closeable(&self) -> &dyn Closeable {
         self
     }
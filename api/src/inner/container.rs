@@ -14,6 +14,11 @@ define_abstract! {
             fn find_control_mut<'a>(&'a mut self, arg: types::FindBy<'a>) -> Option<&'a mut dyn Control>;
             fn find_control<'a>(&'a self, arg: types::FindBy<'a>) -> Option<&'a dyn Control>;
             unsafe fn native_container_id(&self) -> usize;
+            
+            /// All descendants matching `arg`, in depth-first order. The container itself is not included.
+            fn find_controls<'a>(&'a self, arg: types::FindBy<'a>) -> Vec<&'a dyn Control> {
+                crate::traversal::DepthFirst::descendants(self.as_container()).map(|node| node.member).filter(|member| arg.matches(*member)).filter_map(|member| member.is_control()).collect()
+            }
         },
         inner: {
             fn find_control_mut<'a>(&'a mut self, arg: types::FindBy<'a>) -> Option<&'a mut dyn Control>;
@@ -55,37 +60,15 @@ impl<T: ContainerInner> Container for AMember<T> {
 impl<T: ContainerInner + ControlInner> Container for AMember<AControl<T>> {
     #[inline]
     default fn find_control_mut<'a>(&'a mut self, arg: types::FindBy<'a>) -> Option<&'a mut dyn Control> {
-        match arg {
-            types::FindBy::Id(id) => {
-                if self.base.id() == id {
-                    return Some(self);
-                }
-            }
-            types::FindBy::Tag(tag) => {
-                if let Some(mytag) = self.base.tag() {
-                    if tag == mytag {
-                        return Some(self);
-                    }
-                }
-            }
+        if arg.matches(self) {
+            return Some(self);
         }
         self.inner.find_control_mut(arg)
     }
     #[inline]
     default fn find_control<'a>(&'a self, arg: types::FindBy<'a>) -> Option<&'a dyn Control> {
-        match arg {
-            types::FindBy::Id(id) => {
-                if self.base.id() == id {
-                    return Some(self);
-                }
-            }
-            types::FindBy::Tag(tag) => {
-                if let Some(mytag) = self.base.tag() {
-                    if tag == mytag {
-                        return Some(self);
-                    }
-                }
-            }
+        if arg.matches(self) {
+            return Some(self);
         }
         self.inner.find_control(arg)
     }
@@ -33,6 +33,22 @@ define_abstract! {
                     None
                 }
             }
+            fn try_set_child_to(&mut self, index: usize, child: Box<dyn Control>) -> Result<Option<Box<dyn Control>>, crate::Error> {
+                let len = self.len();
+                if index > len {
+                    return Err(crate::Error::IndexOutOfBounds(index, len));
+                }
+                crate::utils::check_backend(self.as_member(), child.as_member())?;
+                Ok(self.set_child_to(index, child))
+            }
+            fn try_remove_child_from(&mut self, index: usize) -> Result<Box<dyn Control>, crate::Error> {
+                let len = self.len();
+                self.remove_child_from(index).ok_or(crate::Error::IndexOutOfBounds(index, len))
+            }
+            fn try_push_child(&mut self, child: Box<dyn Control>) -> Result<(), crate::Error> {
+                let len = self.len();
+                self.try_set_child_to(len, child).map(|_| ())
+            }
         }
         inner: {
             fn len(&self) -> usize;
@@ -9,6 +9,13 @@ define_abstract! {
             fn set_child(&mut self, child: Option<Box<dyn Control>>) -> Option<Box<dyn Control>>;
             fn child(&self) -> Option<&dyn Control>;
             fn child_mut(&mut self) -> Option<&mut dyn Control>;
+            
+            fn try_set_child(&mut self, child: Option<Box<dyn Control>>) -> Result<Option<Box<dyn Control>>, crate::Error> {
+                if let Some(ref child) = child {
+                    crate::utils::check_backend(self.as_member(), child.as_member())?;
+                }
+                Ok(self.set_child(child))
+            }
         }
         inner: {
             fn set_child(&mut self, base: &mut MemberBase, child: Option<Box<dyn Control>>) -> Option<Box<dyn Control>>;
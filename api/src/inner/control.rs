@@ -1,8 +1,10 @@
-use crate::{layout, types, utils};
+use crate::{callbacks::{CallbackId, CallbackList}, layout, style, types, utils};
 
 use super::auto::{HasInner, Spawnable, Abstract};
 use super::container::Container;
 use super::drawable::{Drawable, OuterDrawable};
+use super::has_color::StyleColor;
+use super::has_font::StyleFont;
 use super::has_layout::{HasLayout, HasLayoutInner};
 use super::has_size::{HasSize, HasSizeInner, OnSize};
 use super::has_visibility::{HasVisibility, HasVisibilityInner, OnVisibility};
@@ -14,6 +16,7 @@ use super::frame::MaybeFrame;
 use super::image::MaybeImage;
 use super::list::MaybeList;
 use super::tree::MaybeTree;
+use super::table::MaybeTable;
 use super::progress_bar::MaybeProgressBar;
 use super::text::MaybeText;
 use super::seal::Sealed;
@@ -21,9 +24,11 @@ use super::seal::Sealed;
 pub trait OuterControl: Sealed {
     fn on_added_to_container(&mut self, parent: &dyn Container, x: i32, y: i32, w: u16, h: u16);
     fn on_removed_from_container(&mut self, parent: &dyn Container);
+    /// Applies a newly resolved style, see `style::restyle`. Does nothing if the style did not change.
+    fn on_style_changed(&mut self, style: style::Style);
 
     #[cfg(feature = "markup")]
-    fn fill_from_markup(&mut self, markup: &crate::markup::Markup, registry: &mut crate::markup::MarkupRegistry);
+    fn fill_from_markup(&mut self, markup: &crate::markup::Markup, registry: &mut crate::markup::MarkupRegistry) -> Result<(), crate::Error>;
 }
 impl<T: ControlInner> OuterControl for AMember<AControl<T>> {
     #[inline]
@@ -36,26 +41,52 @@ impl<T: ControlInner> OuterControl for AMember<AControl<T>> {
             }
         }
         self.inner.base.coords = Some((x, y));
-        self.inner.inner.on_added_to_container(&mut self.base, &mut self.inner.base, parent, x, y, w, h)
+        self.inner.base.dirty = true;
+        let adding = crate::runtime::begin_adding();
+        self.inner.inner.on_added_to_container(&mut self.base, &mut self.inner.base, parent, x, y, w, h);
+        // once for the whole added subtree, the children are added by their container from within the call above
+        if adding.is_outermost() {
+            if let Some(app) = crate::runtime::get_dyn() {
+                style::restyle_with_parent(app.stylesheet(), self, Some(parent.computed_style()));
+            }
+        }
     }
     #[inline]
     fn on_removed_from_container(&mut self, parent: &dyn Container) {
         self.inner.inner.on_removed_from_container(&mut self.base, &mut self.inner.base, parent);
         self.inner.base.coords = None;
+        self.inner.base.dirty = true;
+        self.inner.base.measure_cache = None;
+    }
+    fn on_style_changed(&mut self, style: style::Style) {
+        if *self.base.computed_style() == style {
+            return;
+        }
+        self.inner.inner.on_style_changed(&mut self.base, &mut self.inner.base, &style);
+        if self.base.computed_style().foreground != style.foreground {
+            StyleColor::apply_style_color(&mut self.inner, &mut self.base, style.foreground);
+        }
+        if self.base.computed_style().font != style.font {
+            StyleFont::apply_style_font(&mut self.inner, &mut self.base, style.font.as_ref());
+        }
+        self.base.set_computed_style(style);
+        OuterDrawable::mark_dirty(self);
     }
 
     #[cfg(feature = "markup")]
-    default fn fill_from_markup(&mut self, markup: &crate::markup::Markup, registry: &mut crate::markup::MarkupRegistry) {
+    default fn fill_from_markup(&mut self, markup: &crate::markup::Markup, registry: &mut crate::markup::MarkupRegistry) -> Result<(), crate::Error> {
         self.inner.inner.fill_from_markup(&mut self.base, &mut self.inner.base, markup, registry)
     }
 }
 
 pub trait Control: Member + HasSize + HasVisibility + HasLayout + OuterDrawable + OuterControl
-        + MaybeButton + MaybeLinearLayout + MaybeSplitted + MaybeFrame + MaybeImage + MaybeList + MaybeTree + MaybeProgressBar + MaybeText {
+        + MaybeButton + MaybeLinearLayout + MaybeSplitted + MaybeFrame + MaybeImage + MaybeList + MaybeTree + MaybeTable + MaybeProgressBar + MaybeText {
     fn parent(&self) -> Option<&dyn Member>;
     fn parent_mut(&mut self) -> Option<&mut dyn Member>;
     fn root(&self) -> Option<&dyn Member>;
     fn root_mut(&mut self) -> Option<&mut dyn Member>;
+    /// Position within the parent, `None` until the control is added to a container.
+    fn coords(&self) -> Option<(i32, i32)>;
 
     fn as_control(&self) -> &dyn Control;
     fn as_control_mut(&mut self) -> &mut dyn Control;
@@ -65,6 +96,8 @@ pub trait Control: Member + HasSize + HasVisibility + HasLayout + OuterDrawable
 pub trait ControlInner: MemberInner + HasSizeInner + HasVisibilityInner + HasLayoutInner + Drawable + Spawnable {
     fn on_added_to_container(&mut self, member: &mut MemberBase, control: &mut ControlBase, parent: &dyn Container, x: i32, y: i32, w: u16, h: u16);
     fn on_removed_from_container(&mut self, member: &mut MemberBase, control: &mut ControlBase, parent: &dyn Container);
+    /// `member.computed_style()` still holds the previous style at this point.
+    fn on_style_changed(&mut self, _member: &mut MemberBase, _control: &mut ControlBase, _style: &style::Style) {}
 
     fn parent(&self) -> Option<&dyn Member>;
     fn parent_mut(&mut self) -> Option<&mut dyn Member>;
@@ -72,7 +105,7 @@ pub trait ControlInner: MemberInner + HasSizeInner + HasVisibilityInner + HasLay
     fn root_mut(&mut self) -> Option<&mut dyn Member>;
 
     #[cfg(feature = "markup")]
-    fn fill_from_markup(&mut self, member: &mut MemberBase, control: &mut ControlBase, mberarkup: &crate::markup::Markup, registry: &mut crate::markup::MarkupRegistry);
+    fn fill_from_markup(&mut self, member: &mut MemberBase, control: &mut ControlBase, mberarkup: &crate::markup::Markup, registry: &mut crate::markup::MarkupRegistry) -> Result<(), crate::Error>;
 }
 
 #[repr(C)]
@@ -82,9 +115,13 @@ pub struct ControlBase {
     pub measured: (u16, u16),
     pub coords: Option<(i32, i32)>,
     pub skip_draw: bool,
+    /// Needs to be measured again, see `OuterDrawable::mark_dirty`.
+    pub dirty: bool,
+    /// Last measured size, keyed on the parent constraints it was measured with.
+    pub measure_cache: Option<((u16, u16), (u16, u16))>,
 
-    pub on_size: Option<OnSize>,
-    pub on_visibility: Option<OnVisibility>,
+    pub on_size: CallbackList<OnSize>,
+    pub on_visibility: CallbackList<OnVisibility>,
 }
 #[repr(C)]
 pub struct AControl<T: ControlInner> {
@@ -102,9 +139,11 @@ impl Default for ControlBase {
             measured: (0, 0),
             coords: None,
             skip_draw: false,
+            dirty: true,
+            measure_cache: None,
 
-            on_size: None,
-            on_visibility: None,
+            on_size: CallbackList::new(),
+            on_visibility: CallbackList::new(),
         }
     }
 }
@@ -119,6 +158,7 @@ impl<T: ControlInner> AControl<T> {
 impl<T: ControlInner> OuterDrawable for AMember<AControl<T>> {
     #[inline]
     fn draw(&mut self, coords: Option<(i32, i32)>) {
+        let _span = crate::trace::draw(&self.base, coords).entered();
         if coords.is_some() {
             self.inner.base.coords = coords;
         }
@@ -128,12 +168,43 @@ impl<T: ControlInner> OuterDrawable for AMember<AControl<T>> {
     }
     #[inline]
     fn measure(&mut self, w: u16, h: u16) -> (u16, u16, bool) {
-        self.inner.inner.measure(&mut self.base, &mut self.inner.base, w, h)
+        let _span = crate::trace::measure(&self.base, w, h).entered();
+        if !self.inner.base.dirty {
+            if let Some((constraints, (mw, mh))) = self.inner.base.measure_cache {
+                if constraints == (w, h) {
+                    return (mw, mh, false);
+                }
+            }
+        }
+        let measured = self.inner.inner.measure(&mut self.base, &mut self.inner.base, w, h);
+        self.inner.base.measure_cache = Some(((w, h), (measured.0, measured.1)));
+        self.inner.base.dirty = false;
+        measured
     }
     #[inline]
     fn invalidate(&mut self) {
         self.inner.inner.invalidate(&mut self.base, &mut self.inner.base)
     }
+    fn mark_dirty(&mut self) {
+        self.inner.base.dirty = true;
+        self.inner.base.measure_cache = None;
+        let id = self.base.id();
+        match Control::parent_mut(self).and_then(|parent| parent.is_control_mut()) {
+            Some(parent) => parent.mark_dirty(),
+            None => {
+                // the topmost control gets relaid out against its root by `AApplication::run_layout`
+                if crate::runtime::get_dyn().is_some() {
+                    crate::runtime::queue_layout(id);
+                } else {
+                    OuterDrawable::invalidate(self);
+                }
+            }
+        }
+    }
+    #[inline]
+    fn is_dirty(&self) -> bool {
+        self.inner.base.dirty
+    }
     #[inline]
     fn set_skip_draw(&mut self, skip: bool) {
         self.inner.base.skip_draw = skip;
@@ -161,7 +232,9 @@ impl<T: ControlInner> HasLayout for AMember<AControl<T>> {
     }
     fn set_layout(&mut self, width: layout::Size, height: layout::Size) {
         self.inner.base.layout.width = width;
-        self.inner.base.layout.width = height;
+        self.inner.base.layout.height = height;
+        self.inner.inner.on_layout_changed(&mut self.base);
+        self.mark_dirty();
     }
 
     #[inline]
@@ -174,18 +247,20 @@ impl<T: ControlInner> HasLayout for AMember<AControl<T>> {
     }
     #[inline]
     fn layout_margin(&self) -> layout::BoundarySize {
-        self.inner.inner.layout_margin(&self.base)
+        self.inner.inner.layout_margin(&self.base).for_direction(Member::layout_direction(self))
     }
 
     #[inline]
     fn set_layout_width(&mut self, value: layout::Size) {
         self.inner.base.layout.width = value;
         self.inner.inner.on_layout_changed(&mut self.base);
+        self.mark_dirty();
     }
     #[inline]
     fn set_layout_height(&mut self, value: layout::Size) {
         self.inner.base.layout.height = value;
         self.inner.inner.on_layout_changed(&mut self.base);
+        self.mark_dirty();
     }
 
     #[inline]
@@ -209,13 +284,25 @@ impl<T: ControlInner> HasVisibility for AMember<AControl<T>> {
     #[inline]
     fn set_visibility(&mut self, visibility: types::Visibility) {
         if self.inner.inner.on_visibility_set(&mut self.base, visibility) {
+            let changed = self.inner.base.visibility != visibility;
             self.inner.base.visibility = visibility;
+            if changed {
+                self.mark_dirty();
+            }
             self.call_on_visibility::<Self>(visibility);
         }
     }
     #[inline]
     fn on_visibility(&mut self, callback: Option<OnVisibility>) {
-        self.inner.base.on_visibility = callback;
+        self.inner.base.on_visibility.set(callback);
+    }
+    #[inline]
+    fn add_on_visibility(&mut self, callback: OnVisibility) -> CallbackId {
+        self.inner.base.on_visibility.add(callback)
+    }
+    #[inline]
+    fn remove_on_visibility(&mut self, id: CallbackId) -> bool {
+        self.inner.base.on_visibility.remove(id)
     }
     #[inline]
     fn as_has_visibility(&self) -> &dyn HasVisibility {
@@ -244,7 +331,15 @@ impl<T: ControlInner> HasSize for AMember<AControl<T>> {
     }
     #[inline]
     fn on_size(&mut self, callback: Option<OnSize>) {
-        self.inner.base.on_size = callback;
+        self.inner.base.on_size.set(callback);
+    }
+    #[inline]
+    fn add_on_size(&mut self, callback: OnSize) -> CallbackId {
+        self.inner.base.on_size.add(callback)
+    }
+    #[inline]
+    fn remove_on_size(&mut self, id: CallbackId) -> bool {
+        self.inner.base.on_size.remove(id)
     }
 
     #[inline]
@@ -277,6 +372,10 @@ impl<T: ControlInner> Control for AMember<AControl<T>> {
     fn root_mut(&mut self) -> Option<&mut dyn Member> {
         self.inner.inner.root_mut()
     }
+    #[inline]
+    fn coords(&self) -> Option<(i32, i32)> {
+        self.inner.base.coords
+    }
 
     #[inline]
     fn as_control(&self) -> &dyn Control {
@@ -303,16 +402,22 @@ impl<T: ControlInner> AMember<AControl<T>> {
     #[inline]
     pub fn call_on_size<O: Control>(&mut self, w: u16, h: u16) {
         let self2 = self as *mut _ as *mut O;
-        if let Some(ref mut cb) = self.inner.base.on_size {
+        let mut callbacks = self.inner.base.on_size.take();
+        for cb in callbacks.iter_mut() {
+            crate::trace::callback(Some(&self.base), cb);
             (cb.as_mut())(unsafe { &mut *self2 }, w, h);
         }
+        self.inner.base.on_size.restore(callbacks);
     }
     #[inline]
     pub fn call_on_visibility<O: Control>(&mut self, v: types::Visibility) {
         let self2 = self as *mut _ as *mut O;
-        if let Some(ref mut cb) = self.inner.base.on_visibility {
+        let mut callbacks = self.inner.base.on_visibility.take();
+        for cb in callbacks.iter_mut() {
+            crate::trace::callback(Some(&self.base), cb);
             (cb.as_mut())(unsafe { &mut *self2 }, v);
         }
+        self.inner.base.on_visibility.restore(callbacks);
     }
     #[inline]
     pub fn as_control_parts_mut(&mut self) -> (&mut MemberBase, &mut ControlBase, &mut T) {
@@ -356,6 +461,10 @@ impl<II: ControlInner, T: HasInner<I = II> + Abstract + 'static> ControlInner fo
     fn on_removed_from_container(&mut self, member: &mut MemberBase, control: &mut ControlBase, parent: &dyn Container) {
         self.inner_mut().on_removed_from_container(member, control, parent)
     }
+    #[inline]
+    fn on_style_changed(&mut self, member: &mut MemberBase, control: &mut ControlBase, style: &style::Style) {
+        self.inner_mut().on_style_changed(member, control, style)
+    }
 
     #[inline]
     fn parent(&self) -> Option<&dyn Member> {
@@ -375,7 +484,7 @@ impl<II: ControlInner, T: HasInner<I = II> + Abstract + 'static> ControlInner fo
     }
 
     #[cfg(feature = "markup")]
-    fn fill_from_markup(&mut self, member: &mut MemberBase, control: &mut ControlBase, markup: &crate::markup::Markup, registry: &mut crate::markup::MarkupRegistry) {
+    fn fill_from_markup(&mut self, member: &mut MemberBase, control: &mut ControlBase, markup: &crate::markup::Markup, registry: &mut crate::markup::MarkupRegistry) -> Result<(), crate::Error> {
         self.inner_mut().fill_from_markup(member, control, markup, registry)
     }
 }
@@ -7,6 +7,14 @@ pub trait OuterDrawable: Sealed {
     fn draw(&mut self, coords: Option<(i32, i32)>);
     fn measure(&mut self, w: u16, h: u16) -> (u16, u16, bool);
     fn invalidate(&mut self);
+    /// Schedules a relayout of this control, coalesced with the other ones into a single pass per frame.
+    /// The control and all its ancestors are marked dirty, so only they are measured again.
+    ///
+    /// Layout and visibility setters mark the control dirty themselves. Backends call this from every inner
+    /// setter that changes the content size, like `set_label`, `set_font`, `set_image` or `set_progress`,
+    /// and nowhere else, e.g. not from `HasLayoutInner::on_layout_changed`.
+    fn mark_dirty(&mut self);
+    fn is_dirty(&self) -> bool;
     fn set_skip_draw(&mut self, skip: bool);
     fn is_skip_draw(&self) -> bool;
 
@@ -0,0 +1,250 @@
+use crate::callbacks::CallbackId;
+
+use std::future::Future;
+use std::pin::Pin;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, Mutex};
+use std::task::{Context, Poll, Wake, Waker};
+use std::thread;
+
+pub type LocalFuture = Pin<Box<dyn Future<Output = ()>>>;
+
+/// Wakes the UI loop of the backend, can be called from any thread.
+type Notifier = Arc<Mutex<Option<Box<dyn Fn() + Send + Sync>>>>;
+
+struct TaskWaker {
+    woken: AtomicBool,
+    notifier: Notifier,
+}
+impl Wake for TaskWaker {
+    fn wake(self: Arc<Self>) {
+        self.wake_by_ref()
+    }
+    fn wake_by_ref(self: &Arc<Self>) {
+        self.woken.store(true, Ordering::SeqCst);
+        if let Some(ref notify) = *self.notifier.lock().unwrap() {
+            notify();
+        }
+    }
+}
+
+struct Task {
+    id: CallbackId,
+    future: LocalFuture,
+    waker: Arc<TaskWaker>,
+}
+
+/// Futures spawned on the UI thread, polled from the frame loop when woken.
+pub struct LocalExecutor {
+    tasks: Vec<Task>,
+    running: Vec<CallbackId>,
+    notifier: Notifier,
+}
+
+impl LocalExecutor {
+    pub fn new() -> Self {
+        LocalExecutor {
+            tasks: Vec::new(),
+            running: Vec::new(),
+            notifier: Arc::new(Mutex::new(None)),
+        }
+    }
+    /// Sets the function the wakers use to interrupt the backend's frame loop wait.
+    pub fn set_notifier(&mut self, notify: Box<dyn Fn() + Send + Sync>) {
+        *self.notifier.lock().unwrap() = Some(notify);
+    }
+    pub fn spawn(&mut self, future: LocalFuture) -> CallbackId {
+        let id = CallbackId::next();
+        self.tasks.push(Task {
+            id,
+            future,
+            waker: Arc::new(TaskWaker {
+                woken: AtomicBool::new(true),
+                notifier: self.notifier.clone(),
+            }),
+        });
+        id
+    }
+    pub fn cancel(&mut self, id: CallbackId) -> bool {
+        if let Some(index) = self.running.iter().position(|running| *running == id) {
+            self.running.remove(index);
+            return true;
+        }
+        match self.tasks.iter().position(|task| task.id == id) {
+            Some(index) => {
+                self.tasks.remove(index);
+                true
+            }
+            None => false,
+        }
+    }
+    pub fn is_empty(&self) -> bool {
+        self.tasks.is_empty()
+    }
+    pub fn has_woken(&self) -> bool {
+        self.tasks.iter().any(|task| task.waker.woken.load(Ordering::SeqCst))
+    }
+    /// Polls the woken tasks once. Futures may spawn new tasks or access the application while polled.
+    pub(crate) fn run(executor: *mut LocalExecutor) {
+        let mut woken = {
+            let executor = unsafe { &mut *executor };
+            let mut woken = Vec::new();
+            let mut i = 0;
+            while i < executor.tasks.len() {
+                if executor.tasks[i].waker.woken.swap(false, Ordering::SeqCst) {
+                    woken.push(executor.tasks.remove(i));
+                } else {
+                    i += 1;
+                }
+            }
+            executor.running = woken.iter().map(|task| task.id).collect();
+            woken
+        };
+        woken.retain_mut(|task| {
+            if !unsafe { &*executor }.running.contains(&task.id) {
+                return false;
+            }
+            let waker = Waker::from(task.waker.clone());
+            let mut cx = Context::from_waker(&waker);
+            task.future.as_mut().poll(&mut cx).is_pending()
+        });
+        let executor = unsafe { &mut *executor };
+        let running = ::std::mem::take(&mut executor.running);
+        executor.tasks.extend(woken.into_iter().filter(|task| running.contains(&task.id)));
+    }
+}
+impl Default for LocalExecutor {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+struct BackgroundState<R> {
+    result: Option<R>,
+    waker: Option<Waker>,
+}
+
+/// A computation running on a separate thread, to be awaited on the UI thread.
+pub struct Background<R: Send + 'static> {
+    state: Arc<Mutex<BackgroundState<R>>>,
+}
+
+impl<R: Send + 'static> Background<R> {
+    pub fn spawn<F: FnOnce() -> R + Send + 'static>(f: F) -> Self {
+        let state = Arc::new(Mutex::new(BackgroundState { result: None, waker: None }));
+        let state2 = state.clone();
+        thread::spawn(move || {
+            let result = f();
+            let mut state = state2.lock().unwrap();
+            state.result = Some(result);
+            if let Some(waker) = state.waker.take() {
+                waker.wake();
+            }
+        });
+        Background { state }
+    }
+}
+impl<R: Send + 'static> Future for Background<R> {
+    type Output = R;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<R> {
+        let mut state = self.state.lock().unwrap();
+        match state.result.take() {
+            Some(result) => Poll::Ready(result),
+            None => {
+                state.waker = Some(cx.waker().clone());
+                Poll::Pending
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use std::cell::{Cell, RefCell};
+    use std::rc::Rc;
+    use std::sync::atomic::AtomicUsize;
+    use std::sync::mpsc;
+    use std::time::Duration;
+
+    /// Pending until polled for the second time, leaving its waker behind for the test.
+    struct Parked {
+        waker: Rc<RefCell<Option<Waker>>>,
+        polls: Rc<Cell<usize>>,
+    }
+    impl Future for Parked {
+        type Output = ();
+
+        fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<()> {
+            self.polls.set(self.polls.get() + 1);
+            if self.polls.get() > 1 {
+                return Poll::Ready(());
+            }
+            *self.waker.borrow_mut() = Some(cx.waker().clone());
+            Poll::Pending
+        }
+    }
+
+    #[test]
+    fn woken_task_is_polled_again() {
+        let mut executor = LocalExecutor::new();
+        let notified = Arc::new(AtomicUsize::new(0));
+        let counted = notified.clone();
+        executor.set_notifier(Box::new(move || { counted.fetch_add(1, Ordering::SeqCst); }));
+        let (waker, polls) = (Rc::new(RefCell::new(None)), Rc::new(Cell::new(0)));
+        executor.spawn(Box::pin(Parked { waker: waker.clone(), polls: polls.clone() }));
+
+        assert!(executor.has_woken());
+        LocalExecutor::run(&mut executor);
+        assert_eq!(polls.get(), 1);
+        assert!(!executor.has_woken());
+        LocalExecutor::run(&mut executor);
+        assert_eq!(polls.get(), 1);
+
+        waker.borrow_mut().take().unwrap().wake();
+        assert_eq!(notified.load(Ordering::SeqCst), 1);
+        assert!(executor.has_woken());
+        LocalExecutor::run(&mut executor);
+        assert_eq!(polls.get(), 2);
+        assert!(executor.is_empty());
+    }
+
+    #[test]
+    fn cancelled_task_is_not_polled() {
+        let mut executor = LocalExecutor::new();
+        let polls = Rc::new(Cell::new(0));
+        let id = executor.spawn(Box::pin(Parked { waker: Rc::new(RefCell::new(None)), polls: polls.clone() }));
+        assert!(executor.cancel(id));
+        assert!(!executor.cancel(id));
+        LocalExecutor::run(&mut executor);
+        assert_eq!(polls.get(), 0);
+        assert!(executor.is_empty());
+    }
+
+    #[test]
+    fn background_result_is_delivered_to_the_ui_thread() {
+        let mut executor = LocalExecutor::new();
+        let (notify, notified) = mpsc::channel();
+        executor.set_notifier(Box::new(move || { let _ = notify.send(()); }));
+        let ui = thread::current().id();
+        let delivered = Rc::new(Cell::new(None));
+        let result = delivered.clone();
+        executor.spawn(Box::pin(async move {
+            let worker = Background::spawn(|| (thread::current().id(), 6 * 7)).await;
+            result.set(Some((worker, thread::current().id())));
+        }));
+
+        LocalExecutor::run(&mut executor);
+        while delivered.get().is_none() {
+            notified.recv_timeout(Duration::from_secs(10)).expect("the background thread never woke the executor");
+            LocalExecutor::run(&mut executor);
+        }
+        let ((worker, answer), polled_on) = delivered.get().unwrap();
+        assert_eq!(answer, 42);
+        assert_ne!(worker, ui);
+        assert_eq!(polled_on, ui);
+        assert!(executor.is_empty());
+    }
+}
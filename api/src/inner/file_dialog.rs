@@ -0,0 +1,74 @@
+use crate::types;
+
+use super::auto::{HasInner, Abstract};
+use super::has_label::{HasLabel, HasLabelInner};
+use super::member::{AMember, Member, MemberInner};
+
+use std::path::PathBuf;
+
+define! {
+    FileDialog: Member + HasLabel {
+        outer: {
+            fn mode(&self) -> types::FileDialogMode;
+            fn start(self: Box<Self>) -> Result<Vec<PathBuf>, ()>;
+        },
+        inner: {
+            fn with_params(title: &str, mode: types::FileDialogMode, filters: Vec<types::FileFilter>, default_path: Option<PathBuf>, multiple: bool, parent: Option<&dyn Member>) -> Box<dyn FileDialog>;
+            fn mode(&self) -> types::FileDialogMode;
+            fn start(self) -> Result<Vec<PathBuf>, ()>;
+        }
+    }
+}
+
+pub trait NewFileDialog {
+    fn with_params<S: AsRef<str>>(title: S, mode: types::FileDialogMode, filters: Vec<types::FileFilter>, default_path: Option<PathBuf>, multiple: bool, parent: Option<&dyn Member>) -> Box<dyn FileDialog>;
+    fn with_mode<S: AsRef<str>>(title: S, mode: types::FileDialogMode, parent: Option<&dyn Member>) -> Box<dyn FileDialog> {
+        Self::with_params(title, mode, vec![], None, false, parent)
+    }
+    fn start_with_params<S: AsRef<str>>(title: S, mode: types::FileDialogMode, filters: Vec<types::FileFilter>, default_path: Option<PathBuf>, multiple: bool, parent: Option<&dyn Member>) -> Result<Vec<PathBuf>, ()> {
+        Self::with_params(title, mode, filters, default_path, multiple, parent).start()
+    }
+}
+
+impl<II: FileDialogInner, T: HasInner<I = II> + Abstract + 'static> FileDialogInner for T {
+    fn with_params(title: &str, mode: types::FileDialogMode, filters: Vec<types::FileFilter>, default_path: Option<PathBuf>, multiple: bool, parent: Option<&dyn Member>) -> Box<dyn FileDialog> {
+        <<Self as HasInner>::I as FileDialogInner>::with_params(title, mode, filters, default_path, multiple, parent)
+    }
+    fn mode(&self) -> types::FileDialogMode {
+        self.inner().mode()
+    }
+    fn start(self) -> Result<Vec<PathBuf>, ()> {
+        self.into_inner().start()
+    }
+}
+
+impl<T: FileDialogInner> FileDialog for AMember<AFileDialog<T>> {
+    #[inline]
+    fn mode(&self) -> types::FileDialogMode {
+        self.inner.inner.mode()
+    }
+    #[inline]
+    fn start(self: Box<Self>) -> Result<Vec<PathBuf>, ()> {
+        self.inner.start()
+    }
+
+    #[inline]
+    fn as_file_dialog(&self) -> &dyn FileDialog {
+        self
+    }
+    #[inline]
+    fn as_file_dialog_mut(&mut self) -> &mut dyn FileDialog {
+        self
+    }
+    #[inline]
+    fn into_file_dialog(self: Box<Self>) -> Box<dyn FileDialog> {
+        self
+    }
+}
+
+impl<T: FileDialogInner> NewFileDialog for AMember<AFileDialog<T>> {
+    #[inline]
+    fn with_params<S: AsRef<str>>(title: S, mode: types::FileDialogMode, filters: Vec<types::FileFilter>, default_path: Option<PathBuf>, multiple: bool, parent: Option<&dyn Member>) -> Box<dyn FileDialog> {
+        T::with_params(title.as_ref(), mode, filters, default_path, multiple, parent)
+    }
+}
@@ -2,11 +2,13 @@ use super::auto::{HasInner, Abstract, Spawnable};
 use super::container::AContainer;
 use super::container_single::{ASingleContainer, SingleContainer, SingleContainerInner};
 use super::control::{AControl, Control, ControlInner};
+use super::has_color::{HasColor, HasColorInner};
+use super::has_font::{HasFont, HasFontInner};
 use super::has_label::{HasLabel, HasLabelInner};
 use super::member::{AMember, Member};
 
 define! {
-    Frame: SingleContainer + Control + HasLabel {
+    Frame: SingleContainer + Control + HasLabel + HasFont + HasColor {
         constructor: {
             fn with_label<S: AsRef<str>>(label: S) -> Box<dyn Frame>;
         }
@@ -0,0 +1,64 @@
+use crate::types;
+
+use super::auto::{AsAny, HasInner, Abstract};
+use super::member::{AMember, Member, MemberBase, MemberInner};
+
+// The foreground (text) color.
+has_settable!(Color(types::Color): Member);
+
+impl<II: HasColorInner, T: HasInner<I = II> + Abstract + 'static> HasColorInner for T {
+    fn color(&self, member: &MemberBase) -> types::Color {
+        self.inner().color(member)
+    }
+    fn set_color(&mut self, member: &mut MemberBase, arg0: types::Color) {
+        self.inner_mut().set_color(member, arg0)
+    }
+}
+
+/// Hands the resolved foreground of the style to the backends of the members having a color, see `OuterControl::on_style_changed`.
+/// `None` hands back the color the backend had before the style first set one.
+pub(crate) trait StyleColor {
+    fn apply_style_color(&mut self, member: &mut MemberBase, color: Option<types::Color>);
+}
+impl<T> StyleColor for T {
+    default fn apply_style_color(&mut self, _member: &mut MemberBase, _color: Option<types::Color>) {}
+}
+impl<T: HasColorInner> StyleColor for T {
+    fn apply_style_color(&mut self, member: &mut MemberBase, color: Option<types::Color>) {
+        let color = match color {
+            Some(color) => {
+                if member.unstyled_mut().foreground.is_none() {
+                    let unstyled = self.color(member);
+                    member.unstyled_mut().foreground = Some(unstyled);
+                }
+                color
+            }
+            None => match member.unstyled_mut().foreground.take() {
+                Some(unstyled) => unstyled,
+                None => return,
+            },
+        };
+        self.set_color(member, color)
+    }
+}
+
+impl<T: HasColorInner> HasColor for AMember<T> {
+    fn color(&self) -> types::Color {
+        self.inner.color(&self.base)
+    }
+    /// Sets the foreground of the own style, so it wins over the stylesheet and is inherited by the children.
+    fn set_color(&mut self, arg0: types::Color) {
+        let mut style = self.base.style().clone();
+        style.foreground = Some(arg0);
+        Member::set_style(self, style);
+    }
+    fn as_has_color(&self) -> &dyn HasColor {
+        self
+    }
+    fn as_has_color_mut(&mut self) -> &mut dyn HasColor {
+        self
+    }
+    fn into_has_color(self: Box<Self>) -> Box<dyn HasColor> {
+        self
+    }
+}
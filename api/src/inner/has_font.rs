@@ -0,0 +1,65 @@
+use crate::types;
+
+use super::auto::{AsAny, HasInner, Abstract};
+use super::member::{AMember, Member, MemberBase, MemberInner};
+
+use std::borrow::Cow;
+
+has_settable!(Font(Cow<'_, types::Font>): Member);
+
+impl<II: HasFontInner, T: HasInner<I = II> + Abstract + 'static> HasFontInner for T {
+    fn font(&self, member: &MemberBase) -> Cow<'_, types::Font> {
+        self.inner().font(member)
+    }
+    fn set_font(&mut self, member: &mut MemberBase, arg0: Cow<types::Font>) {
+        self.inner_mut().set_font(member, arg0)
+    }
+}
+
+/// Hands the resolved font of the style to the backends of the members having a font, see `OuterControl::on_style_changed`.
+/// `None` hands back the font the backend had before the style first set one.
+pub(crate) trait StyleFont {
+    fn apply_style_font(&mut self, member: &mut MemberBase, font: Option<&types::Font>);
+}
+impl<T> StyleFont for T {
+    default fn apply_style_font(&mut self, _member: &mut MemberBase, _font: Option<&types::Font>) {}
+}
+impl<T: HasFontInner> StyleFont for T {
+    fn apply_style_font(&mut self, member: &mut MemberBase, font: Option<&types::Font>) {
+        let font = match font {
+            Some(font) => {
+                if member.unstyled_mut().font.is_none() {
+                    let unstyled = self.font(member).into_owned();
+                    member.unstyled_mut().font = Some(unstyled);
+                }
+                Cow::Borrowed(font)
+            }
+            None => match member.unstyled_mut().font.take() {
+                Some(unstyled) => Cow::Owned(unstyled),
+                None => return,
+            },
+        };
+        self.set_font(member, font)
+    }
+}
+
+impl<T: HasFontInner> HasFont for AMember<T> {
+    fn font(&self) -> Cow<'_, types::Font> {
+        self.inner.font(&self.base)
+    }
+    /// Sets the font of the own style, so it wins over the stylesheet and is inherited by the children.
+    fn set_font(&mut self, arg0: Cow<types::Font>) {
+        let mut style = self.base.style().clone();
+        style.font = Some(arg0.into_owned());
+        Member::set_style(self, style);
+    }
+    fn as_has_font(&self) -> &dyn HasFont {
+        self
+    }
+    fn as_has_font_mut(&mut self) -> &mut dyn HasFont {
+        self
+    }
+    fn into_has_font(self: Box<Self>) -> Box<dyn HasFont> {
+        self
+    }
+}
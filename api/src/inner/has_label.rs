@@ -3,7 +3,22 @@ use super::member::{AMember, Member, MemberBase, MemberInner};
 
 use std::borrow::Cow;
 
-has_settable!(Label(Cow<'_, str>): Member);
+has_settable!(Label(Cow<'_, str>): Member {
+    outer: {
+        /// The localized message of the label, if it was set with `set_label_key`.
+        fn label_key(&self) -> Option<&crate::l10n::Message> {
+            self.as_base().label_key()
+        }
+        /// Sets the label to `key` formatted in the current locale, and again on every `Application::set_locale`,
+        /// with the same plural count and arguments. A later `set_label` drops the key.
+        fn set_label_key(&mut self, key: crate::l10n::Message) {
+            let label = crate::l10n::translate(&key);
+            self.set_label(label.into());
+            self.as_base_mut().set_label_key(Some(key));
+        }
+    },
+    inner: {}
+});
 
 impl<II: HasLabelInner, T: HasInner<I = II> + Abstract + 'static> HasLabelInner for T {
     fn label(&self, member: &MemberBase) -> Cow<str> {
@@ -19,6 +34,7 @@ impl<T: HasLabelInner> HasLabel for AMember<T> {
         self.inner.label(&self.base)
     }
     fn set_label(&mut self, arg0: Cow<str>) {
+        self.base.set_label_key(None);
         self.inner.set_label(&mut self.base, arg0)
     }
     fn as_has_label(&self) -> &dyn HasLabel {
@@ -9,6 +9,7 @@ has_private!(Layout(layout::Size, layout::Size): Member {
         fn layout_height(&self) -> layout::Size;
         fn set_layout_width(&mut self, value: layout::Size);
         fn set_layout_height(&mut self, value: layout::Size);
+        /// Mirrored for right-to-left members, see `BoundarySize::for_direction`.
         fn layout_margin(&self) -> layout::BoundarySize;
     },
     inner: {
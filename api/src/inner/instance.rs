@@ -0,0 +1,234 @@
+use crate::callbacks::{AsyncFeeder, Callback, CallbackId};
+
+use super::application::Application;
+use super::auto::OnFrame;
+
+use std::env;
+use std::io;
+use std::path::PathBuf;
+use std::sync::mpsc;
+
+#[cfg(unix)]
+use std::fs;
+#[cfg(unix)]
+use std::io::{Read, Write};
+#[cfg(unix)]
+use std::net::Shutdown;
+#[cfg(unix)]
+use std::os::unix::fs::{DirBuilderExt, MetadataExt};
+#[cfg(unix)]
+use std::os::unix::net::{UnixListener, UnixStream};
+#[cfg(unix)]
+use std::path::Path;
+#[cfg(unix)]
+use std::sync::atomic::{AtomicBool, Ordering};
+#[cfg(unix)]
+use std::sync::Arc;
+#[cfg(unix)]
+use std::thread;
+#[cfg(unix)]
+use std::time::Duration;
+
+/// How long the primary instance waits for a later launch to send its arguments.
+#[cfg(unix)]
+const READ_TIMEOUT: Duration = Duration::from_secs(1);
+/// Two launches at once may both find no instance, the one that loses the bind connects again.
+#[cfg(unix)]
+const ACQUIRE_ATTEMPTS: usize = 3;
+
+on!(InstanceArgs (&mut dyn Application, &[String]));
+
+/// Outcome of claiming the single instance slot of an application name.
+pub enum InstanceRole {
+    /// No other instance runs, this one receives the arguments of the later launches.
+    Primary(SingleInstance),
+    /// The arguments were forwarded to the already running instance.
+    Secondary,
+}
+
+/// The primary instance end of the single instance channel, keyed by the application name.
+pub struct SingleInstance {
+    path: PathBuf,
+    receiver: mpsc::Receiver<Vec<String>>,
+    #[cfg(unix)]
+    sender: mpsc::Sender<Vec<String>>,
+    #[cfg(unix)]
+    listener: Option<UnixListener>,
+    #[cfg(unix)]
+    stop: Arc<AtomicBool>,
+}
+
+impl SingleInstance {
+    /// Where the channel of the named application lives: the runtime dir of the user,
+    /// or a directory only the user can access in the temp dir.
+    pub fn socket_path(name: &str) -> PathBuf {
+        let name: String = name.chars().map(|c| if c.is_ascii_alphanumeric() || c == '-' || c == '_' { c } else { '_' }).collect();
+        let dir = match env::var_os("XDG_RUNTIME_DIR") {
+            Some(dir) => PathBuf::from(dir),
+            #[cfg(unix)]
+            None => env::temp_dir().join(format!("plygui-{}", current_uid().unwrap_or(0))),
+            #[cfg(not(unix))]
+            None => env::temp_dir(),
+        };
+        dir.join(format!("plygui-{}.sock", name))
+    }
+    /// Forwards `args` to the running instance of `name`, or becomes that instance if there is none.
+    /// Only instances of the same user are talked to.
+    #[cfg(unix)]
+    pub fn acquire(name: &str, args: &[String]) -> io::Result<InstanceRole> {
+        let path = Self::socket_path(name);
+        let uid = current_uid()?;
+        if let Some(dir) = path.parent() {
+            private_dir(dir, uid)?;
+        }
+        for _ in 0..ACQUIRE_ATTEMPTS {
+            match UnixStream::connect(&path) {
+                Ok(mut stream) => {
+                    if stream.peer_cred()?.uid != uid {
+                        return Err(io::Error::new(io::ErrorKind::PermissionDenied, "the instance socket belongs to another user"));
+                    }
+                    for arg in args {
+                        stream.write_all(arg.as_bytes())?;
+                        stream.write_all(&[0])?;
+                    }
+                    stream.shutdown(Shutdown::Write)?;
+                    return Ok(InstanceRole::Secondary);
+                }
+                Err(ref e) if e.kind() == io::ErrorKind::ConnectionRefused => {
+                    // stale socket of an instance that did not shut down cleanly
+                    let _ = fs::remove_file(&path);
+                }
+                Err(ref e) if e.kind() == io::ErrorKind::NotFound => {}
+                Err(e) => return Err(e),
+            }
+            match UnixListener::bind(&path) {
+                Ok(listener) => {
+                    let (sender, receiver) = mpsc::channel();
+                    return Ok(InstanceRole::Primary(SingleInstance {
+                        path,
+                        receiver,
+                        sender,
+                        listener: Some(listener),
+                        stop: Arc::new(AtomicBool::new(false)),
+                    }));
+                }
+                Err(ref e) if e.kind() == io::ErrorKind::AddrInUse => continue,
+                Err(e) => return Err(e),
+            }
+        }
+        Err(io::Error::new(io::ErrorKind::AddrInUse, "the instance socket is taken but does not answer"))
+    }
+    /// Without a local IPC channel every launch is a primary one.
+    #[cfg(not(unix))]
+    pub fn acquire(name: &str, _args: &[String]) -> io::Result<InstanceRole> {
+        let (_, receiver) = mpsc::channel();
+        Ok(InstanceRole::Primary(SingleInstance {
+            path: Self::socket_path(name),
+            receiver,
+        }))
+    }
+    /// Starts accepting the forwarded arguments, waking the frame loop through `feeder` on each launch.
+    #[cfg(unix)]
+    pub fn listen(&mut self, mut feeder: AsyncFeeder<OnFrame>) {
+        let listener = match self.listener.take() {
+            Some(listener) => listener,
+            None => return,
+        };
+        let uid = match current_uid() {
+            Ok(uid) => uid,
+            Err(_) => return,
+        };
+        let sender = self.sender.clone();
+        let stop = self.stop.clone();
+        thread::spawn(move || {
+            for stream in listener.incoming() {
+                if stop.load(Ordering::SeqCst) {
+                    break;
+                }
+                let mut data = Vec::new();
+                let read = stream.and_then(|mut stream| {
+                    if stream.peer_cred()?.uid != uid {
+                        return Err(io::ErrorKind::PermissionDenied.into());
+                    }
+                    stream.set_read_timeout(Some(READ_TIMEOUT))?;
+                    stream.read_to_end(&mut data)
+                });
+                if read.is_err() {
+                    continue;
+                }
+                let args = data.split(|b| *b == 0).filter(|arg| !arg.is_empty()).map(|arg| String::from_utf8_lossy(arg).into_owned()).collect();
+                if sender.send(args).is_err() || feeder.feed((|_: &mut dyn Application| false).into()).is_err() {
+                    break;
+                }
+            }
+        });
+    }
+    #[cfg(not(unix))]
+    pub fn listen(&mut self, _feeder: AsyncFeeder<OnFrame>) {}
+
+    pub(crate) fn try_recv(&self) -> Option<Vec<String>> {
+        self.receiver.try_recv().ok()
+    }
+}
+
+/// The user this process runs as, read off the credentials of a socket pair to itself.
+#[cfg(unix)]
+fn current_uid() -> io::Result<u32> {
+    let (stream, _) = UnixStream::pair()?;
+    Ok(stream.peer_cred()?.uid)
+}
+
+/// Creates the directory of the socket, accessible by the user only, or checks that the existing one is.
+#[cfg(unix)]
+fn private_dir(dir: &Path, uid: u32) -> io::Result<()> {
+    match fs::DirBuilder::new().mode(0o700).create(dir) {
+        Ok(()) => Ok(()),
+        Err(ref e) if e.kind() == io::ErrorKind::AlreadyExists => {
+            let meta = fs::symlink_metadata(dir)?;
+            if !meta.is_dir() || meta.uid() != uid || meta.mode() & 0o077 != 0 {
+                Err(io::Error::new(io::ErrorKind::PermissionDenied, "the instance socket directory is not private to the user"))
+            } else {
+                Ok(())
+            }
+        }
+        Err(e) => Err(e),
+    }
+}
+
+impl Drop for SingleInstance {
+    fn drop(&mut self) {
+        #[cfg(unix)]
+        {
+            self.stop.store(true, Ordering::SeqCst);
+            if self.listener.is_none() {
+                // unblocks the accepting thread
+                let _ = UnixStream::connect(&self.path);
+            }
+            let _ = ::std::fs::remove_file(&self.path);
+        }
+    }
+}
+
+#[cfg(all(test, unix))]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn later_launch_forwards_to_the_primary() {
+        let name = format!("instance-test-{}", ::std::process::id());
+        let primary = match SingleInstance::acquire(&name, &[]).unwrap() {
+            InstanceRole::Primary(primary) => primary,
+            InstanceRole::Secondary => panic!("no instance runs yet"),
+        };
+        let path = SingleInstance::socket_path(&name);
+        let meta = fs::metadata(path.parent().unwrap()).unwrap();
+        assert_eq!(meta.uid(), current_uid().unwrap());
+        assert_eq!(meta.mode() & 0o077, 0);
+        match SingleInstance::acquire(&name, &["--open".to_owned()]).unwrap() {
+            InstanceRole::Secondary => {}
+            InstanceRole::Primary(_) => panic!("the first instance still runs"),
+        }
+        drop(primary);
+        assert!(!path.exists());
+    }
+}
@@ -12,6 +12,14 @@ impl<T: ItemClickableInner + MemberInner> ItemClickable for AMember<AControl<T>>
         self.inner.inner.on_item_click(cb)
     }
     #[inline]
+    default fn add_on_item_click(&mut self, cb: OnItemClick) -> CallbackId {
+        self.inner.inner.add_on_item_click(cb)
+    }
+    #[inline]
+    default fn remove_on_item_click(&mut self, id: CallbackId) -> bool {
+        self.inner.inner.remove_on_item_click(id)
+    }
+    #[inline]
     default fn item_click(&mut self, i: &[usize], parent: &mut dyn Control, skip_callbacks: bool) {
         self.inner.inner.item_click(i, parent, skip_callbacks)
     }
@@ -37,4 +45,10 @@ impl<II: ItemClickableInner, T: HasInner<I = II> + Abstract + 'static> ItemClick
     default fn on_item_click(&mut self, callback: Option<OnItemClick>) {
         self.inner_mut().on_item_click(callback)
     }
+    default fn add_on_item_click(&mut self, callback: OnItemClick) -> CallbackId {
+        self.inner_mut().add_on_item_click(callback)
+    }
+    default fn remove_on_item_click(&mut self, id: CallbackId) -> bool {
+        self.inner_mut().remove_on_item_click(id)
+    }
 }
\ No newline at end of file
@@ -1,4 +1,5 @@
-use crate::{ids};
+use crate::{a11y, ids, l10n, layout, style, types};
+use crate::callbacks::{Callback, CallbackId, CallbackList};
 
 use super::auto::{AsAny, HasInner, Abstract};
 use super::application::Application;
@@ -6,16 +7,20 @@ use super::container::MaybeContainer;
 use super::control::MaybeControl;
 use super::closeable::MaybeCloseable;
 use super::clickable::MaybeClickable;
+use super::item_clickable::MaybeItemClickable;
 use super::has_native_id::{HasNativeId, HasNativeIdInner};
 use super::has_size::MaybeHasSize;
 use super::has_visibility::MaybeHasVisibility;
 use super::window::MaybeWindow;
 use super::message::MaybeMessage;
+use super::file_dialog::MaybeFileDialog;
 use super::tray::MaybeTray;
 use super::seal::Sealed;
 use super::has_image::MaybeHasImage;
 use super::has_layout::MaybeHasLayout;
 use super::has_label::MaybeHasLabel;
+use super::has_font::MaybeHasFont;
+use super::has_color::MaybeHasColor;
 use super::has_progress::MaybeHasProgress;
 
 #[cfg(feature = "type_check")]
@@ -27,11 +32,48 @@ use std::marker::PhantomData;
 use std::rc::Rc;
 
 pub trait Member: HasNativeId + AsAny + Sealed 
-        + MaybeControl + MaybeContainer + MaybeHasSize + MaybeHasVisibility + MaybeHasImage + MaybeHasLayout + MaybeHasLabel + MaybeHasProgress + MaybeCloseable + MaybeClickable
-        + MaybeWindow + MaybeTray + MaybeMessage {
+        + MaybeControl + MaybeContainer + MaybeHasSize + MaybeHasVisibility + MaybeHasImage + MaybeHasLayout + MaybeHasLabel + MaybeHasFont + MaybeHasColor + MaybeHasProgress + MaybeCloseable + MaybeClickable + MaybeItemClickable
+        + MaybeWindow + MaybeTray + MaybeMessage + MaybeFileDialog {
     fn id(&self) -> ids::Id;
     fn tag(&self) -> Option<Cow<str>>;
     fn set_tag(&mut self, tag: Option<Cow<str>>);
+    
+    /// Style classes, matched by `types::Selector`. Unlike the tag, many members can share a class.
+    fn classes(&self) -> &[String];
+    fn has_class(&self, class: &str) -> bool;
+    /// Returns `false` if the class was already there. The stylesheet rules follow on the next `Application::restyle`.
+    fn add_class(&mut self, class: Cow<str>) -> bool;
+    /// The properties applied through the class are cleared on the next `Application::restyle`.
+    fn remove_class(&mut self, class: &str) -> bool;
+    
+    /// Own style of the member, overriding the stylesheet rules matching it.
+    fn style(&self) -> &style::Style;
+    /// Replaces the own style and restyles the member with its descendants.
+    fn set_style(&mut self, style: style::Style);
+    /// The style resolved from the stylesheet, the own style and the inherited properties.
+    fn computed_style(&self) -> &style::Style;
+    
+    /// The effective direction, never `Direction::Inherit`: the own one if set, otherwise the one of the parent,
+    /// `Direction::LeftToRight` at the root.
+    fn layout_direction(&self) -> layout::Direction;
+    fn set_layout_direction(&mut self, direction: layout::Direction);
+    
+    /// The own accessible name if set, otherwise the non-empty `HasLabel` label.
+    fn accessible_name(&self) -> Option<Cow<'_, str>>;
+    /// `None` falls back to the label again.
+    fn set_accessible_name(&mut self, name: Option<Cow<str>>);
+    fn accessible_description(&self) -> Option<&str>;
+    fn set_accessible_description(&mut self, description: Option<Cow<str>>);
+    /// The own role if set, otherwise the one for the kind of the member, see `a11y::Role::of`.
+    fn accessible_role(&self) -> a11y::Role;
+    fn set_accessible_role(&mut self, role: Option<a11y::Role>);
+    fn accessible_state(&self) -> a11y::State;
+
+    /// Subscribes to the events bubbling through this member, see `utils::dispatch_event`.
+    fn on_event(&mut self, callback: Option<OnEvent>);
+    fn add_on_event(&mut self, callback: OnEvent) -> CallbackId;
+    /// Unsubscribes the callback with the given id from any of this member's events.
+    fn remove_callback(&mut self, id: CallbackId) -> bool;
 
     #[cfg(feature = "type_check")]
     unsafe fn type_id(&self) -> TypeId;
@@ -44,15 +86,36 @@ pub trait Member: HasNativeId + AsAny + Sealed
     fn as_base_mut(&mut self) -> &mut MemberBase;
 }
 
-pub trait MemberInner: HasNativeIdInner + Sized + 'static {}
+pub trait MemberInner: HasNativeIdInner + Sized + 'static {
+    /// Hands the effective accessible name, description and role to the toolkit whenever one of them is set,
+    /// see `Member::set_accessible_name`. The label fallback of the name is left to the toolkit.
+    fn on_accessibility_changed(&mut self, _base: &mut MemberBase, _name: Option<&str>, _description: Option<&str>, _role: a11y::Role) {}
+}
+
+on!(Event (&mut dyn Member, &mut types::BubblingEvent));
 
 impl<T: MemberInner> Abstract for AMember<T> {}
-impl<II: MemberInner, T: HasInner<I = II> + Abstract + 'static> MemberInner for T {}
+impl<II: MemberInner, T: HasInner<I = II> + Abstract + 'static> MemberInner for T {
+    fn on_accessibility_changed(&mut self, base: &mut MemberBase, name: Option<&str>, description: Option<&str>, role: a11y::Role) {
+        self.inner_mut().on_accessibility_changed(base, name, description, role)
+    }
+}
 
 #[repr(C)]
 pub struct MemberBase {
     id: ids::Id,
     tag: Option<String>,
+    classes: Vec<String>,
+    style: style::Style,
+    computed_style: style::Style,
+    /// The foreground and the font the backend had before the style first set them, restored once it no longer does.
+    unstyled: style::Style,
+    direction: layout::Direction,
+    label_key: Option<l10n::Message>,
+    accessible_name: Option<String>,
+    accessible_description: Option<String>,
+    accessible_role: Option<a11y::Role>,
+    on_event: CallbackList<OnEvent>,
     
     _as_member: unsafe fn(&MemberBase) -> &dyn Member,
     _as_member_mut: unsafe fn(&mut MemberBase) -> &mut dyn Member,
@@ -70,16 +133,27 @@ impl MemberBase {
         MemberBase {
             id: ids::Id::next(),
             tag: None,
+            classes: Vec::new(),
+            style: Default::default(),
+            computed_style: Default::default(),
+            unstyled: Default::default(),
+            direction: Default::default(),
+            label_key: None,
+            accessible_name: None,
+            accessible_description: None,
+            accessible_role: None,
+            on_event: CallbackList::new(),
             _as_member: crate::utils::base_to_member::<T>,
             _as_member_mut: crate::utils::base_to_member_mut::<T>,
             _no_threads: PhantomData,
         }
     }
-    pub fn application<A: Application>(&self) -> &A {
-        crate::runtime::get().expect("Application not initialized. Please run `plygui::imp::Application::with_name()` first.")
+    /// The Application of the current thread, `None` if it is not created yet or this is not the UI thread.
+    pub fn application<A: Application>(&self) -> Option<&A> {
+        crate::runtime::get().map(|a| &*a)
     }
-    pub fn application_mut<A: Application>(&mut self) -> &mut A {
-        crate::runtime::get().expect("Application not initialized. Please run `plygui::imp::Application::with_name()` first.")
+    pub fn application_mut<A: Application>(&mut self) -> Option<&mut A> {
+        crate::runtime::get()
     }
     pub fn id(&self) -> ids::Id {
         self.id
@@ -90,6 +164,82 @@ impl MemberBase {
     pub fn set_tag(&mut self, tag: Option<Cow<str>>) {
         self.tag = tag.map(|t| t.into());
     }
+    pub fn classes(&self) -> &[String] {
+        self.classes.as_slice()
+    }
+    pub fn has_class(&self, class: &str) -> bool {
+        self.classes.iter().any(|c| c == class)
+    }
+    pub fn add_class(&mut self, class: Cow<str>) -> bool {
+        if self.has_class(&class) {
+            return false;
+        }
+        self.classes.push(class.into());
+        true
+    }
+    pub fn remove_class(&mut self, class: &str) -> bool {
+        let len = self.classes.len();
+        self.classes.retain(|c| c != class);
+        self.classes.len() != len
+    }
+    pub fn style(&self) -> &style::Style {
+        &self.style
+    }
+    pub fn set_style(&mut self, style: style::Style) {
+        self.style = style;
+    }
+    pub fn computed_style(&self) -> &style::Style {
+        &self.computed_style
+    }
+    pub(crate) fn set_computed_style(&mut self, style: style::Style) {
+        self.computed_style = style;
+    }
+    pub(crate) fn unstyled_mut(&mut self) -> &mut style::Style {
+        &mut self.unstyled
+    }
+    /// The own direction, possibly `Direction::Inherit`. See `Member::layout_direction` for the effective one.
+    pub fn direction(&self) -> layout::Direction {
+        self.direction
+    }
+    pub fn set_direction(&mut self, direction: layout::Direction) {
+        self.direction = direction;
+    }
+    /// The `l10n` message the label is translated from, see `HasLabel::set_label_key`.
+    pub fn label_key(&self) -> Option<&l10n::Message> {
+        self.label_key.as_ref()
+    }
+    pub fn set_label_key(&mut self, key: Option<l10n::Message>) {
+        self.label_key = key;
+    }
+    /// The own accessible name, see `Member::accessible_name` for the effective one.
+    pub fn accessible_name(&self) -> Option<&str> {
+        self.accessible_name.as_deref()
+    }
+    pub fn set_accessible_name(&mut self, name: Option<String>) {
+        self.accessible_name = name;
+    }
+    pub fn accessible_description(&self) -> Option<&str> {
+        self.accessible_description.as_deref()
+    }
+    pub fn set_accessible_description(&mut self, description: Option<String>) {
+        self.accessible_description = description;
+    }
+    /// The own role, see `Member::accessible_role` for the effective one.
+    pub fn accessible_role(&self) -> Option<a11y::Role> {
+        self.accessible_role
+    }
+    pub fn set_accessible_role(&mut self, role: Option<a11y::Role>) {
+        self.accessible_role = role;
+    }
+    pub fn call_on_event(&mut self, event: &mut types::BubblingEvent) {
+        let mut callbacks = self.on_event.take();
+        for cb in callbacks.iter_mut() {
+            crate::trace::callback(Some(self), cb);
+            let member = unsafe { (self._as_member_mut)(&mut *(self as *mut MemberBase)) };
+            (cb.as_mut())(member, event);
+        }
+        self.on_event.restore(callbacks);
+    }
     #[inline]
     pub fn as_any(&self) -> &dyn Any {
         unsafe { (self._as_member)(self) }.as_any()
@@ -124,6 +274,121 @@ impl<T: MemberInner> Member for AMember<T> {
     fn set_tag(&mut self, tag: Option<Cow<str>>) {
         self.base.set_tag(tag)
     }
+    fn classes(&self) -> &[String] {
+        self.base.classes()
+    }
+    fn has_class(&self, class: &str) -> bool {
+        self.base.has_class(class)
+    }
+    fn add_class(&mut self, class: Cow<str>) -> bool {
+        self.base.add_class(class)
+    }
+    fn remove_class(&mut self, class: &str) -> bool {
+        self.base.remove_class(class)
+    }
+    fn style(&self) -> &style::Style {
+        self.base.style()
+    }
+    fn set_style(&mut self, style: style::Style) {
+        self.base.set_style(style);
+        let sheet = crate::runtime::get_dyn().map(|app| app.stylesheet().clone()).unwrap_or_default();
+        style::restyle(&sheet, self);
+    }
+    fn computed_style(&self) -> &style::Style {
+        self.base.computed_style()
+    }
+    fn layout_direction(&self) -> layout::Direction {
+        match self.base.direction() {
+            layout::Direction::Inherit => self.is_control().and_then(|control| control.parent()).map_or(layout::Direction::LeftToRight, |parent| parent.layout_direction()),
+            direction => direction,
+        }
+    }
+    fn set_layout_direction(&mut self, direction: layout::Direction) {
+        if self.base.direction() == direction {
+            return;
+        }
+        self.base.set_direction(direction);
+        let effective = self.layout_direction();
+        let mut walk = crate::traversal::DepthFirstMut::new(self.as_member_mut());
+        while let Some(node) = walk.next() {
+            // the descendants inheriting the direction flip along, the ones setting their own keep it
+            if node.depth() > 0 && (node.member.as_base().direction() != layout::Direction::Inherit || node.member.layout_direction() != effective) {
+                continue;
+            }
+            if let Some(control) = node.member.is_control_mut() {
+                control.mark_dirty();
+            }
+        }
+    }
+    fn accessible_name(&self) -> Option<Cow<'_, str>> {
+        match self.base.accessible_name() {
+            Some(name) => Some(name.into()),
+            None => self.is_has_label().map(|label| label.label()).filter(|label| !label.is_empty()),
+        }
+    }
+    fn set_accessible_name(&mut self, name: Option<Cow<str>>) {
+        self.base.set_accessible_name(name.map(|name| name.into_owned()));
+        self.accessibility_changed();
+    }
+    fn accessible_description(&self) -> Option<&str> {
+        self.base.accessible_description()
+    }
+    fn set_accessible_description(&mut self, description: Option<Cow<str>>) {
+        self.base.set_accessible_description(description.map(|description| description.into_owned()));
+        self.accessibility_changed();
+    }
+    fn accessible_role(&self) -> a11y::Role {
+        self.base.accessible_role().unwrap_or_else(|| a11y::Role::of(self.as_member()))
+    }
+    fn set_accessible_role(&mut self, role: Option<a11y::Role>) {
+        self.base.set_accessible_role(role);
+        self.accessibility_changed();
+    }
+    fn accessible_state(&self) -> a11y::State {
+        a11y::State::of(self.as_member())
+    }
+    fn on_event(&mut self, callback: Option<OnEvent>) {
+        self.base.on_event.set(callback)
+    }
+    fn add_on_event(&mut self, callback: OnEvent) -> CallbackId {
+        self.base.on_event.add(callback)
+    }
+    fn remove_callback(&mut self, id: CallbackId) -> bool {
+        if self.base.on_event.remove(id) {
+            return true;
+        }
+        if let Some(has_size) = self.is_has_size_mut() {
+            if has_size.remove_on_size(id) {
+                return true;
+            }
+        }
+        if let Some(has_visibility) = self.is_has_visibility_mut() {
+            if has_visibility.remove_on_visibility(id) {
+                return true;
+            }
+        }
+        if let Some(clickable) = self.is_clickable_mut() {
+            if clickable.remove_on_click(id) {
+                return true;
+            }
+        }
+        if let Some(item_clickable) = self.is_item_clickable_mut() {
+            if item_clickable.remove_on_item_click(id) {
+                return true;
+            }
+        }
+        if let Some(closeable) = self.is_closeable_mut() {
+            if closeable.remove_on_close(id) {
+                return true;
+            }
+        }
+        if let Some(window) = self.is_window_mut() {
+            if window.remove_on_scale_change(id) {
+                return true;
+            }
+        }
+        false
+    }
     #[cfg(feature = "type_check")]
     unsafe fn type_id(&self) -> TypeId {
         self.inner.native_id().type_id()
@@ -173,6 +438,12 @@ impl<T: MemberInner> AMember<T> {
             base: MemberBase::with_type::<Self>(),
         }
     }
+    fn accessibility_changed(&mut self) {
+        let name = Member::accessible_name(self).map(|name| name.into_owned());
+        let description = self.base.accessible_description().map(String::from);
+        let role = Member::accessible_role(self);
+        self.inner.on_accessibility_changed(&mut self.base, name.as_deref(), description.as_deref(), role);
+    }
 }
 
 impl<T: MemberInner> HasInner for AMember<T> {
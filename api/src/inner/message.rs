@@ -8,34 +8,51 @@ define! {
     Message: Member + HasLabel {
         outer: {
             fn severity(&self) -> types::MessageSeverity;
-            fn start(self: Box<Self>) -> Result<String, ()>;
+            fn input(&self) -> &types::MessageInput;
+            fn start(self: Box<Self>) -> Result<types::MessageResponse, ()>;
         },
         inner: {
-            fn with_actions(content: types::TextContent, severity: types::MessageSeverity, actions: Vec<(String, callbacks::Action)>, parent: Option<&dyn Member>) -> Box<dyn Message>;
+            fn with_input(content: types::TextContent, severity: types::MessageSeverity, input: types::MessageInput, actions: Vec<(String, callbacks::Action)>, parent: Option<&dyn Member>) -> Box<dyn Message>;
             fn severity(&self) -> types::MessageSeverity;
-            fn start(self) -> Result<String, ()>;
+            fn input(&self) -> &types::MessageInput;
+            fn start(self) -> Result<types::MessageResponse, ()>;
         }
     }
 }
 
 pub trait NewMessage {
-    fn with_actions(content: types::TextContent, severity: types::MessageSeverity, actions: Vec<(String, callbacks::Action)>, parent: Option<&dyn Member>) -> Box<dyn Message>;
+    fn with_input(content: types::TextContent, severity: types::MessageSeverity, input: types::MessageInput, actions: Vec<(String, callbacks::Action)>, parent: Option<&dyn Member>) -> Box<dyn Message>;
+    fn with_actions(content: types::TextContent, severity: types::MessageSeverity, actions: Vec<(String, callbacks::Action)>, parent: Option<&dyn Member>) -> Box<dyn Message> {
+        Self::with_input(content, severity, types::MessageInput::None, actions, parent)
+    }
     fn with_content(content: types::TextContent, severity: types::MessageSeverity, parent: Option<&dyn Member>) -> Box<dyn Message> {
         Self::with_actions(content, severity, vec![], parent)
     }
-    fn start_with_actions(content: types::TextContent, severity: types::MessageSeverity, actions: Vec<(String, callbacks::Action)>, parent: Option<&dyn Member>) -> Result<String, ()> {
+    fn start_with_actions(content: types::TextContent, severity: types::MessageSeverity, actions: Vec<(String, callbacks::Action)>, parent: Option<&dyn Member>) -> Result<types::MessageResponse, ()> {
         Self::with_actions(content, severity, actions, parent).start()
     }
+    fn ask_text<S: Into<String>>(content: types::TextContent, default: S, actions: Vec<(String, callbacks::Action)>, parent: Option<&dyn Member>) -> Result<types::MessageResponse, ()> {
+        Self::with_input(content, types::MessageSeverity::Question, types::MessageInput::Text(default.into()), actions, parent).start()
+    }
+    fn ask_choice(content: types::TextContent, options: Vec<String>, default: usize, actions: Vec<(String, callbacks::Action)>, parent: Option<&dyn Member>) -> Result<types::MessageResponse, ()> {
+        Self::with_input(content, types::MessageSeverity::Question, types::MessageInput::Choice(options, default), actions, parent).start()
+    }
+    fn confirm<S: Into<String>>(content: types::TextContent, checkbox: S, checked: bool, actions: Vec<(String, callbacks::Action)>, parent: Option<&dyn Member>) -> Result<types::MessageResponse, ()> {
+        Self::with_input(content, types::MessageSeverity::Question, types::MessageInput::Confirm(checkbox.into(), checked), actions, parent).start()
+    }
 }
 
 impl<II: MessageInner, T: HasInner<I = II> + Abstract + 'static> MessageInner for T {
-    fn with_actions(content: types::TextContent, severity: types::MessageSeverity, actions: Vec<(String, callbacks::Action)>, parent: Option<&dyn Member>) -> Box<dyn Message> {
-        <<Self as HasInner>::I as MessageInner>::with_actions(content, severity, actions, parent)
+    fn with_input(content: types::TextContent, severity: types::MessageSeverity, input: types::MessageInput, actions: Vec<(String, callbacks::Action)>, parent: Option<&dyn Member>) -> Box<dyn Message> {
+        <<Self as HasInner>::I as MessageInner>::with_input(content, severity, input, actions, parent)
     }
     fn severity(&self) -> types::MessageSeverity {
         self.inner().severity()
     }
-    fn start(self) -> Result<String, ()> {
+    fn input(&self) -> &types::MessageInput {
+        self.inner().input()
+    }
+    fn start(self) -> Result<types::MessageResponse, ()> {
         self.into_inner().start()
     }
 }
@@ -46,7 +63,11 @@ impl<T: MessageInner> Message for AMember<AMessage<T>> {
         self.inner.inner.severity()
     }
     #[inline]
-    fn start(self: Box<Self>) -> Result<String, ()> {
+    fn input(&self) -> &types::MessageInput {
+        self.inner.inner.input()
+    }
+    #[inline]
+    fn start(self: Box<Self>) -> Result<types::MessageResponse, ()> {
         self.inner.start()
     }
 
@@ -66,7 +87,7 @@ impl<T: MessageInner> Message for AMember<AMessage<T>> {
 
 impl<T: MessageInner> NewMessage for AMember<AMessage<T>> {
     #[inline]
-    fn with_actions(content: types::TextContent, severity: types::MessageSeverity, actions: Vec<(String, callbacks::Action)>, parent: Option<&dyn Member>) -> Box<dyn Message> {
-        T::with_actions(content, severity, actions, parent)
+    fn with_input(content: types::TextContent, severity: types::MessageSeverity, input: types::MessageInput, actions: Vec<(String, callbacks::Action)>, parent: Option<&dyn Member>) -> Box<dyn Message> {
+        T::with_input(content, severity, input, actions, parent)
     }
 }
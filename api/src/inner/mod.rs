@@ -7,6 +7,8 @@ pub mod container;
 pub mod container_multi;
 pub mod container_single;
 
+pub mod has_color;
+pub mod has_font;
 pub mod has_image;
 pub mod has_label;
 pub mod has_layout;
@@ -21,6 +23,10 @@ pub mod closeable;
 pub mod item_clickable;
 pub mod adapted;
 pub mod adapter;
+pub mod clipboard;
+pub mod timer;
+pub mod executor;
+pub mod instance;
 
 pub mod application;
 pub mod button;
@@ -31,6 +37,7 @@ pub mod list;
 pub mod tree;
 pub mod table;
 pub mod message;
+pub mod file_dialog;
 pub mod progress_bar;
 pub mod splitted;
 pub mod text;
@@ -1,10 +1,12 @@
 use super::auto::{HasInner, Abstract, Spawnable};
 use super::control::{AControl, Control, ControlInner};
+use super::has_color::{HasColor, HasColorInner};
+use super::has_font::{HasFont, HasFontInner};
 use super::has_label::{HasLabel, HasLabelInner};
 use super::member::{AMember, Member};
 
 define! {
-    Text: Control + HasLabel {
+    Text: Control + HasLabel + HasFont + HasColor {
         constructor: {
             fn with_text<S: AsRef<str>>(label: S) -> Box<dyn Text>;
         }
@@ -0,0 +1,206 @@
+use crate::callbacks::{Callback, CallbackId};
+
+use super::application::Application;
+
+use std::time::{Duration, Instant};
+
+on!(Timer (&mut dyn Application));
+
+/// Source of the current time for the timers, replaceable for tests.
+pub trait Clock: 'static {
+    fn now(&self) -> Instant;
+}
+
+#[derive(Debug, Clone, Copy, Default)]
+pub struct SystemClock;
+
+impl Clock for SystemClock {
+    #[inline]
+    fn now(&self) -> Instant {
+        Instant::now()
+    }
+}
+
+struct Timer {
+    deadline: Instant,
+    interval: Duration,
+    repeat: bool,
+    callback: OnTimer,
+}
+
+/// One-shot and repeating timers of an `Application`, fired from its frame loop.
+pub struct Timers {
+    clock: Box<dyn Clock>,
+    timers: Vec<Timer>,
+    running: Vec<CallbackId>,
+}
+
+impl Timers {
+    pub fn with_clock(clock: Box<dyn Clock>) -> Self {
+        Timers {
+            clock,
+            timers: Vec::new(),
+            running: Vec::new(),
+        }
+    }
+    pub fn set_clock(&mut self, clock: Box<dyn Clock>) {
+        self.clock = clock;
+    }
+    pub fn now(&self) -> Instant {
+        self.clock.now()
+    }
+    pub fn add(&mut self, interval: Duration, repeat: bool, callback: OnTimer) -> CallbackId {
+        let id = callback.id();
+        self.timers.push(Timer {
+            deadline: self.clock.now() + interval,
+            interval,
+            repeat,
+            callback,
+        });
+        id
+    }
+    pub fn remove(&mut self, id: CallbackId) -> bool {
+        if let Some(index) = self.running.iter().position(|running| *running == id) {
+            self.running.remove(index);
+            return true;
+        }
+        match self.timers.iter().position(|timer| timer.callback.id() == id) {
+            Some(index) => {
+                self.timers.remove(index);
+                true
+            }
+            None => false,
+        }
+    }
+    pub fn is_empty(&self) -> bool {
+        self.timers.is_empty()
+    }
+    /// The earliest moment a timer is due, for backends that can sleep until then.
+    pub fn next_deadline(&self) -> Option<Instant> {
+        self.timers.iter().map(|timer| timer.deadline).min()
+    }
+    /// Fires the timers that are due, in deadline order. A repeating timer is rescheduled from its previous
+    /// deadline, so it fires once per elapsed interval, unless it was removed from within its callback.
+    pub(crate) fn run(timers: *mut Timers, app: &mut dyn Application) {
+        let now = unsafe { &*timers }.now();
+        // zero interval timers fire once per run instead of spinning
+        let mut next_run = Vec::new();
+        while let Some(mut timer) = unsafe { &mut *timers }.take_due(now) {
+            crate::trace::callback(None, &timer.callback);
+            (timer.callback.as_mut())(app);
+            unsafe { &mut *timers }.finish(timer, &mut next_run);
+        }
+        unsafe { &mut *timers }.timers.append(&mut next_run);
+    }
+    /// Takes out the earliest timer due at `now`, marking it running.
+    fn take_due(&mut self, now: Instant) -> Option<Timer> {
+        let index = self
+            .timers
+            .iter()
+            .enumerate()
+            .filter(|(_, timer)| timer.deadline <= now)
+            .min_by_key(|(_, timer)| timer.deadline)
+            .map(|(index, _)| index)?;
+        let timer = self.timers.remove(index);
+        self.running.push(timer.callback.id());
+        Some(timer)
+    }
+    /// Puts a fired repeating timer back, unless it was removed while running.
+    fn finish(&mut self, mut timer: Timer, next_run: &mut Vec<Timer>) {
+        let removed = match self.running.iter().position(|running| *running == timer.callback.id()) {
+            Some(index) => {
+                self.running.remove(index);
+                false
+            }
+            None => true,
+        };
+        if timer.repeat && !removed {
+            timer.deadline += timer.interval;
+            if timer.interval.is_zero() {
+                next_run.push(timer);
+            } else {
+                self.timers.push(timer);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use std::cell::Cell;
+    use std::rc::Rc;
+
+    struct TestClock(Rc<Cell<Instant>>);
+    impl Clock for TestClock {
+        fn now(&self) -> Instant {
+            self.0.get()
+        }
+    }
+
+    fn timers() -> (Timers, Rc<Cell<Instant>>) {
+        let now = Rc::new(Cell::new(Instant::now()));
+        (Timers::with_clock(Box::new(TestClock(now.clone()))), now)
+    }
+    fn noop() -> OnTimer {
+        (|_: &mut dyn Application| {}).into()
+    }
+    /// Fires the due timers the way `run` does, without an application to pass to the callbacks.
+    fn fire(timers: &mut Timers) -> Vec<CallbackId> {
+        let now = timers.now();
+        let mut fired = Vec::new();
+        let mut next_run = Vec::new();
+        while let Some(timer) = timers.take_due(now) {
+            fired.push(timer.callback.id());
+            timers.finish(timer, &mut next_run);
+        }
+        timers.timers.append(&mut next_run);
+        fired
+    }
+
+    #[test]
+    fn repeating_timer_fires_once_per_elapsed_interval() {
+        let (mut timers, now) = timers();
+        let interval = Duration::from_millis(10);
+        timers.add(interval, true, noop());
+        now.set(now.get() + interval * 10);
+        assert_eq!(fire(&mut timers).len(), 10);
+        assert_eq!(fire(&mut timers).len(), 0);
+        now.set(now.get() + interval);
+        assert_eq!(fire(&mut timers).len(), 1);
+    }
+    #[test]
+    fn one_shot_timer_fires_once() {
+        let (mut timers, now) = timers();
+        timers.add(Duration::from_millis(10), false, noop());
+        now.set(now.get() + Duration::from_millis(50));
+        assert_eq!(fire(&mut timers).len(), 1);
+        assert!(timers.is_empty());
+    }
+    #[test]
+    fn timers_fire_in_deadline_order() {
+        let (mut timers, now) = timers();
+        let fast = timers.add(Duration::from_millis(10), true, noop());
+        let slow = timers.add(Duration::from_millis(15), true, noop());
+        now.set(now.get() + Duration::from_millis(25));
+        assert_eq!(fire(&mut timers), vec![fast, slow, fast]);
+    }
+    #[test]
+    fn zero_interval_timer_fires_once_per_run() {
+        let (mut timers, _) = timers();
+        timers.add(Duration::from_millis(0), true, noop());
+        assert_eq!(fire(&mut timers).len(), 1);
+        assert_eq!(fire(&mut timers).len(), 1);
+    }
+    #[test]
+    fn timer_removed_while_running_is_not_rescheduled() {
+        let (mut timers, now) = timers();
+        let id = timers.add(Duration::from_millis(10), true, noop());
+        now.set(now.get() + Duration::from_millis(10));
+        let timer = timers.take_due(now.get()).unwrap();
+        assert!(timers.remove(id));
+        timers.finish(timer, &mut Vec::new());
+        assert!(timers.is_empty());
+    }
+}
@@ -5,21 +5,45 @@ use super::container_single::{ASingleContainer, SingleContainer, SingleContainer
 use super::has_label::{HasLabel, HasLabelInner};
 use super::has_size::{HasSize, HasSizeInner, OnSize};
 use super::has_visibility::{HasVisibility, HasVisibilityInner, OnVisibility};
-use super::member::{AMember, Member};
+use super::member::{AMember, Member, MemberBase};
 use super::application::Application;
 
+use crate::callbacks::{Callback, CallbackId, CallbackList};
 use crate::types;
 
+on!(ScaleChange (&mut dyn Window, f32));
+
 define! {
     Window: HasSize + HasVisibility + SingleContainer + HasLabel + Closeable {
         base: {
             pub visibility: types::Visibility,
-            pub on_size: Option<OnSize>,
-            pub on_visibility: Option<OnVisibility>,
+            pub on_size: CallbackList<OnSize>,
+            pub on_visibility: CallbackList<OnVisibility>,
+            pub scale_factor: f32,
+            pub on_scale_change: CallbackList<OnScaleChange>,
+        },
+        outer: {
+            /// Physical pixels per logical one on the display the window is on.
+            fn scale_factor(&self) -> f32;
+            fn on_scale_change(&mut self, callback: Option<OnScaleChange>);
+            fn add_on_scale_change(&mut self, callback: OnScaleChange) -> CallbackId;
+            fn remove_on_scale_change(&mut self, id: CallbackId) -> bool;
+            
+            /// Snapshots the control tree of this window, see `utils::dump_layout`.
+            fn dump_layout(&self) -> crate::layout::LayoutDump {
+                crate::utils::dump_layout(self.as_member())
+            }
+            /// Snapshots the accessibility tree of this window, see `a11y::snapshot`.
+            fn accessibility_tree(&self) -> crate::a11y::AccessibleNode {
+                crate::a11y::snapshot(self.as_member())
+            }
         },
         inner: {
             fn size(&self) -> (u16, u16);
             fn position(&self) -> (i32, i32);
+
+            /// The native window keeps its physical size on a new display, so backends update the logical one `size` returns.
+            fn on_scale_factor_set(&mut self, _base: &mut MemberBase, _old: f32, _new: f32) {}
         }
         constructor: {
             fn with_params<S: AsRef<str>>(app: &mut dyn Application, title: S, window_size: types::WindowStartSize, menu: types::Menu) -> Box<dyn Window>;
@@ -34,8 +58,10 @@ impl<T: WindowInner> AWindow<T> {
         AWindow {
             base: WindowBase {
                 visibility: types::Visibility::Visible,
-                on_size: None,
-                on_visibility: None,
+                on_size: CallbackList::new(),
+                on_visibility: CallbackList::new(),
+                scale_factor: 1.0,
+                on_scale_change: CallbackList::new(),
             },
             inner,
         }
@@ -52,7 +78,13 @@ impl<T: WindowInner> HasVisibility for AMember<AContainer<ASingleContainer<AClos
         }
     }
     fn on_visibility(&mut self, callback: Option<OnVisibility>) {
-        self.inner.inner.inner.inner.base.on_visibility = callback;
+        self.inner.inner.inner.inner.base.on_visibility.set(callback);
+    }
+    fn add_on_visibility(&mut self, callback: OnVisibility) -> CallbackId {
+        self.inner.inner.inner.inner.base.on_visibility.add(callback)
+    }
+    fn remove_on_visibility(&mut self, id: CallbackId) -> bool {
+        self.inner.inner.inner.inner.base.on_visibility.remove(id)
     }
 
     fn as_has_visibility(&self) -> &dyn HasVisibility {
@@ -80,7 +112,15 @@ impl<T: WindowInner> HasSize for AMember<AContainer<ASingleContainer<ACloseable<
     }
     #[inline]
     fn on_size(&mut self, callback: Option<OnSize>) {
-        self.inner.inner.inner.inner.base.on_size = callback;
+        self.inner.inner.inner.inner.base.on_size.set(callback);
+    }
+    #[inline]
+    fn add_on_size(&mut self, callback: OnSize) -> CallbackId {
+        self.inner.inner.inner.inner.base.on_size.add(callback)
+    }
+    #[inline]
+    fn remove_on_size(&mut self, id: CallbackId) -> bool {
+        self.inner.inner.inner.inner.base.on_size.remove(id)
     }
 
     #[inline]
@@ -97,6 +137,22 @@ impl<T: WindowInner> HasSize for AMember<AContainer<ASingleContainer<ACloseable<
     }
 }
 impl<T: WindowInner> Window for AMember<AContainer<ASingleContainer<ACloseable<AWindow<T>>>>> {
+    #[inline]
+    fn scale_factor(&self) -> f32 {
+        self.inner.inner.inner.inner.base.scale_factor
+    }
+    #[inline]
+    fn on_scale_change(&mut self, callback: Option<OnScaleChange>) {
+        self.inner.inner.inner.inner.base.on_scale_change.set(callback);
+    }
+    #[inline]
+    fn add_on_scale_change(&mut self, callback: OnScaleChange) -> CallbackId {
+        self.inner.inner.inner.inner.base.on_scale_change.add(callback)
+    }
+    #[inline]
+    fn remove_on_scale_change(&mut self, id: CallbackId) -> bool {
+        self.inner.inner.inner.inner.base.on_scale_change.remove(id)
+    }
     fn as_window(&self) -> &dyn Window {
         self
     }
@@ -117,6 +173,9 @@ impl<II: WindowInner, T: HasInner<I = II> + Abstract + 'static> WindowInner for
     fn position(&self) -> (i32, i32) {
         self.inner().position()
     }
+    fn on_scale_factor_set(&mut self, base: &mut MemberBase, old: f32, new: f32) {
+        self.inner_mut().on_scale_factor_set(base, old, new)
+    }
 }
 impl<T: WindowInner> NewWindow for AMember<AContainer<ASingleContainer<ACloseable<AWindow<T>>>>> {
     #[inline]
@@ -127,14 +186,46 @@ impl<T: WindowInner> NewWindow for AMember<AContainer<ASingleContainer<ACloseabl
 impl<T: WindowInner> AMember<AContainer<ASingleContainer<ACloseable<AWindow<T>>>>> {
     pub fn call_on_size<O: Window>(&mut self, w: u16, h: u16) {
         let self2 = self as *mut _ as *mut O;
-        if let Some(ref mut cb) = self.inner.inner.inner.inner.base.on_size {
+        let mut callbacks = self.inner.inner.inner.inner.base.on_size.take();
+        for cb in callbacks.iter_mut() {
+            crate::trace::callback(Some(&self.base), cb);
             (cb.as_mut())(unsafe { &mut *self2 }, w, h);
         }
+        self.inner.inner.inner.inner.base.on_size.restore(callbacks);
+    }
+    /// Backends call this when the window moves to a display with another scale factor.
+    /// The physical size stays, so the logical one changes and the controls are laid out again.
+    /// Factors that are not finite and positive are ignored.
+    pub fn set_scale_factor<O: Window>(&mut self, scale_factor: f32) {
+        let old = self.inner.inner.inner.inner.base.scale_factor;
+        if !scale_factor.is_finite() || scale_factor <= 0.0 || old == scale_factor {
+            return;
+        }
+        self.inner.inner.inner.inner.base.scale_factor = scale_factor;
+        let old_size = self.inner.inner.inner.inner.inner.size();
+        self.inner.inner.inner.inner.inner.on_scale_factor_set(&mut self.base, old, scale_factor);
+        if let Some(child) = SingleContainer::child_mut(self) {
+            child.mark_dirty();
+        }
+        let (w, h) = self.inner.inner.inner.inner.inner.size();
+        if (w, h) != old_size {
+            self.call_on_size::<O>(w, h);
+        }
+        let self2 = self as *mut _ as *mut O;
+        let mut callbacks = self.inner.inner.inner.inner.base.on_scale_change.take();
+        for cb in callbacks.iter_mut() {
+            crate::trace::callback(Some(&self.base), cb);
+            (cb.as_mut())(unsafe { &mut *self2 }, scale_factor);
+        }
+        self.inner.inner.inner.inner.base.on_scale_change.restore(callbacks);
     }
     pub fn call_on_visibility<O: Window>(&mut self, v: types::Visibility) {
         let self2 = self as *mut _ as *mut O;
-        if let Some(ref mut cb) = self.inner.inner.inner.inner.base.on_visibility {
+        let mut callbacks = self.inner.inner.inner.inner.base.on_visibility.take();
+        for cb in callbacks.iter_mut() {
+            crate::trace::callback(Some(&self.base), cb);
             (cb.as_mut())(unsafe { &mut *self2 }, v);
         }
+        self.inner.inner.inner.inner.base.on_visibility.restore(callbacks);
     }
 }
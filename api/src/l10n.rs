@@ -0,0 +1,665 @@
+//! Localized messages, see `Application::localizer`.
+//!
+//! Catalogs are read from gettext `.po` files, one per locale. Messages are looked up by their `msgid`,
+//! the plural forms are chosen with the `Plural-Forms` rule of the catalog, and `{name}` placeholders
+//! are replaced with the given arguments.
+
+use std::borrow::Cow;
+use std::collections::{BTreeMap, HashMap};
+use std::fmt::{self, Write};
+use std::fs;
+use std::path::Path;
+
+/// Named arguments of a message, e.g. `&[("name", &"Ann")]` for `"Hello, {name}!"`.
+pub type Args<'a> = &'a [(&'a str, &'a dyn fmt::Display)];
+
+/// The messages of one locale.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Catalog {
+    locale: String,
+    plural: PluralRule,
+    messages: HashMap<String, Vec<String>>,
+}
+
+impl Catalog {
+    pub fn new<S: Into<String>>(locale: S) -> Self {
+        Catalog { locale: locale.into(), plural: PluralRule::default(), messages: HashMap::new() }
+    }
+    pub fn locale(&self) -> &str {
+        self.locale.as_str()
+    }
+    pub fn plural_rule(&self) -> &PluralRule {
+        &self.plural
+    }
+    pub fn set_plural_rule(&mut self, rule: PluralRule) {
+        self.plural = rule;
+    }
+    pub fn insert<K: Into<String>, V: Into<String>>(&mut self, key: K, message: V) {
+        self.messages.insert(key.into(), vec![message.into()]);
+    }
+    /// `forms` are indexed by the plural rule of the catalog.
+    pub fn insert_plural<K: Into<String>>(&mut self, key: K, forms: Vec<String>) {
+        self.messages.insert(key.into(), forms);
+    }
+    pub fn len(&self) -> usize {
+        self.messages.len()
+    }
+    pub fn is_empty(&self) -> bool {
+        self.messages.is_empty()
+    }
+    pub fn message(&self, key: &str) -> Option<&str> {
+        self.messages.get(key).and_then(|forms| forms.first()).map(|form| form.as_str())
+    }
+    pub fn message_plural(&self, key: &str, n: u64) -> Option<&str> {
+        self.messages.get(key).and_then(|forms| forms.get(self.plural.index(n)).or_else(|| forms.last())).map(|form| form.as_str())
+    }
+
+    pub fn load_po<S: Into<String>, P: AsRef<Path>>(locale: S, path: P) -> Result<Catalog, crate::Error> {
+        let path = path.as_ref();
+        let source = fs::read_to_string(path).map_err(|e| crate::Error::Io(format!("{}: {}", path.display(), e)))?;
+        Catalog::parse_po(locale, &source)
+    }
+    /// Reads the gettext `.po` syntax. Fuzzy and untranslated entries are skipped, `msgctxt` is prepended to the key
+    /// with the `\u{4}` separator, as gettext does.
+    pub fn parse_po<S: Into<String>>(locale: S, source: &str) -> Result<Catalog, crate::Error> {
+        let mut catalog = Catalog::new(locale);
+        let mut entry = PoEntry::default();
+        let mut field = PoField::None;
+        for (number, line) in source.lines().enumerate() {
+            let number = number + 1;
+            let line = line.trim();
+            if line.is_empty() {
+                continue;
+            }
+            if line.starts_with('#') {
+                if entry.has_translation() {
+                    entry.flush(&mut catalog)?;
+                }
+                if line.starts_with("#,") && line.contains("fuzzy") {
+                    entry.fuzzy = true;
+                }
+                continue;
+            }
+            if line.starts_with('"') {
+                let value = unquote(line, number)?;
+                match field {
+                    PoField::Context => entry.context.get_or_insert_with(String::new).push_str(&value),
+                    PoField::Id => entry.id.get_or_insert_with(String::new).push_str(&value),
+                    PoField::IdPlural => {}
+                    PoField::Str(index) => entry.strs.entry(index).or_insert_with(String::new).push_str(&value),
+                    PoField::None => return Err(po_error(number, "string outside of an entry")),
+                }
+                continue;
+            }
+            let (keyword, rest) = line.split_at(line.find(|c: char| c.is_whitespace()).unwrap_or(line.len()));
+            if (keyword == "msgctxt" || keyword == "msgid") && entry.has_translation() {
+                entry.flush(&mut catalog)?;
+            }
+            let value = unquote(rest.trim(), number)?;
+            field = match keyword {
+                "msgctxt" => {
+                    entry.context = Some(value);
+                    PoField::Context
+                }
+                "msgid" => {
+                    entry.id = Some(value);
+                    PoField::Id
+                }
+                "msgid_plural" => PoField::IdPlural,
+                "msgstr" => {
+                    entry.strs.insert(0, value);
+                    PoField::Str(0)
+                }
+                _ if keyword.starts_with("msgstr[") && keyword.ends_with(']') => {
+                    let index = keyword["msgstr[".len()..keyword.len() - 1].parse().map_err(|_| po_error(number, "invalid plural index"))?;
+                    entry.strs.insert(index, value);
+                    PoField::Str(index)
+                }
+                _ => return Err(po_error(number, &format!("unknown keyword '{}'", keyword))),
+            };
+        }
+        entry.flush(&mut catalog)?;
+        Ok(catalog)
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+enum PoField {
+    None,
+    Context,
+    Id,
+    IdPlural,
+    Str(usize),
+}
+
+#[derive(Default)]
+struct PoEntry {
+    context: Option<String>,
+    id: Option<String>,
+    strs: BTreeMap<usize, String>,
+    fuzzy: bool,
+}
+
+impl PoEntry {
+    fn has_translation(&self) -> bool {
+        !self.strs.is_empty()
+    }
+    fn flush(&mut self, catalog: &mut Catalog) -> Result<(), crate::Error> {
+        let entry = ::std::mem::take(self);
+        let id = match entry.id {
+            Some(id) => id,
+            None => return Ok(()),
+        };
+        if id.is_empty() {
+            // the header, of which only the plural rule matters
+            let header = entry.strs.get(&0).map(|h| h.as_str()).unwrap_or("");
+            if let Some(forms) = header.lines().find_map(|line| line.trim().strip_prefix("Plural-Forms:")) {
+                if let Some(plural) = forms.split(';').find_map(|part| part.trim().strip_prefix("plural=")) {
+                    catalog.plural = PluralRule::parse(plural)?;
+                }
+            }
+            return Ok(());
+        }
+        if entry.fuzzy || entry.strs.values().all(|s| s.is_empty()) {
+            return Ok(());
+        }
+        let key = match entry.context {
+            Some(context) => format!("{}\u{4}{}", context, id),
+            None => id,
+        };
+        catalog.messages.insert(key, entry.strs.into_values().collect());
+        Ok(())
+    }
+}
+
+fn po_error(line: usize, reason: &str) -> crate::Error {
+    crate::Error::Parse(format!("PO line {}: {}", line, reason))
+}
+
+fn unquote(quoted: &str, line: usize) -> Result<String, crate::Error> {
+    if quoted.len() < 2 || !quoted.starts_with('"') || !quoted.ends_with('"') {
+        return Err(po_error(line, "expected a quoted string"));
+    }
+    let mut out = String::new();
+    let mut chars = quoted[1..quoted.len() - 1].chars();
+    while let Some(c) = chars.next() {
+        if c != '\\' {
+            out.push(c);
+            continue;
+        }
+        match chars.next() {
+            Some('n') => out.push('\n'),
+            Some('t') => out.push('\t'),
+            Some('r') => out.push('\r'),
+            Some('"') => out.push('"'),
+            Some('\\') => out.push('\\'),
+            _ => return Err(po_error(line, "invalid escape")),
+        }
+    }
+    Ok(out)
+}
+
+/// The C-like `plural=` expression of gettext, e.g. `n != 1` or `n%10==1 && n%100!=11 ? 0 : 1`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct PluralRule {
+    expr: Expr,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+enum Expr {
+    N,
+    Number(i64),
+    Not(Box<Expr>),
+    Binary(Op, Box<Expr>, Box<Expr>),
+    Conditional(Box<Expr>, Box<Expr>, Box<Expr>),
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Op {
+    Or,
+    And,
+    Eq,
+    Ne,
+    Lt,
+    Le,
+    Gt,
+    Ge,
+    Add,
+    Sub,
+    Mul,
+    Div,
+    Rem,
+}
+
+impl Op {
+    fn precedence(self) -> u8 {
+        match self {
+            Op::Or => 1,
+            Op::And => 2,
+            Op::Eq | Op::Ne => 3,
+            Op::Lt | Op::Le | Op::Gt | Op::Ge => 4,
+            Op::Add | Op::Sub => 5,
+            Op::Mul | Op::Div | Op::Rem => 6,
+        }
+    }
+    fn apply(self, a: i64, b: i64) -> i64 {
+        match self {
+            Op::Or => (a != 0 || b != 0) as i64,
+            Op::And => (a != 0 && b != 0) as i64,
+            Op::Eq => (a == b) as i64,
+            Op::Ne => (a != b) as i64,
+            Op::Lt => (a < b) as i64,
+            Op::Le => (a <= b) as i64,
+            Op::Gt => (a > b) as i64,
+            Op::Ge => (a >= b) as i64,
+            Op::Add => a.wrapping_add(b),
+            Op::Sub => a.wrapping_sub(b),
+            Op::Mul => a.wrapping_mul(b),
+            Op::Div => a.checked_div(b).unwrap_or(0),
+            Op::Rem => a.checked_rem(b).unwrap_or(0),
+        }
+    }
+}
+
+impl Expr {
+    fn eval(&self, n: i64) -> i64 {
+        match *self {
+            Expr::N => n,
+            Expr::Number(v) => v,
+            Expr::Not(ref e) => (e.eval(n) == 0) as i64,
+            Expr::Binary(op, ref a, ref b) => op.apply(a.eval(n), b.eval(n)),
+            Expr::Conditional(ref c, ref a, ref b) => {
+                if c.eval(n) != 0 {
+                    a.eval(n)
+                } else {
+                    b.eval(n)
+                }
+            }
+        }
+    }
+}
+
+/// Deepest nesting of parentheses, `!` and `?:` accepted in a plural rule, real ones stay far below it.
+const MAX_PLURAL_DEPTH: usize = 32;
+/// Longest plural rule accepted, bounding the size of the evaluated expression tree.
+const MAX_PLURAL_LEN: usize = 1024;
+
+struct PluralParser<'a> {
+    source: &'a str,
+    rest: &'a str,
+    depth: usize,
+}
+
+impl<'a> PluralParser<'a> {
+    fn error(&self, reason: &str) -> crate::Error {
+        crate::Error::Parse(format!("Plural rule '{}': {}", self.source, reason))
+    }
+    fn skip_whitespace(&mut self) {
+        self.rest = self.rest.trim_start();
+    }
+    fn eat(&mut self, token: &str) -> bool {
+        self.skip_whitespace();
+        if self.rest.starts_with(token) {
+            self.rest = &self.rest[token.len()..];
+            true
+        } else {
+            false
+        }
+    }
+    fn peek_op(&mut self) -> Option<(Op, usize)> {
+        self.skip_whitespace();
+        const OPS: &[(&str, Op)] = &[
+            ("||", Op::Or),
+            ("&&", Op::And),
+            ("==", Op::Eq),
+            ("!=", Op::Ne),
+            ("<=", Op::Le),
+            (">=", Op::Ge),
+            ("<", Op::Lt),
+            (">", Op::Gt),
+            ("+", Op::Add),
+            ("-", Op::Sub),
+            ("*", Op::Mul),
+            ("/", Op::Div),
+            ("%", Op::Rem),
+        ];
+        OPS.iter().find(|(token, _)| self.rest.starts_with(token)).map(|(token, op)| (*op, token.len()))
+    }
+    /// Runs `parse` one nesting level deeper, failing past `MAX_PLURAL_DEPTH`.
+    fn nested<F: FnOnce(&mut Self) -> Result<Expr, crate::Error>>(&mut self, parse: F) -> Result<Expr, crate::Error> {
+        if self.depth >= MAX_PLURAL_DEPTH {
+            return Err(self.error("nested too deeply"));
+        }
+        self.depth += 1;
+        let expr = parse(self);
+        self.depth -= 1;
+        expr
+    }
+    fn conditional(&mut self) -> Result<Expr, crate::Error> {
+        self.nested(Self::ternary)
+    }
+    fn ternary(&mut self) -> Result<Expr, crate::Error> {
+        let condition = self.binary(1)?;
+        if !self.eat("?") {
+            return Ok(condition);
+        }
+        let then = self.conditional()?;
+        if !self.eat(":") {
+            return Err(self.error("expected ':'"));
+        }
+        let otherwise = self.conditional()?;
+        Ok(Expr::Conditional(Box::new(condition), Box::new(then), Box::new(otherwise)))
+    }
+    fn binary(&mut self, min_precedence: u8) -> Result<Expr, crate::Error> {
+        let mut left = self.unary()?;
+        while let Some((op, len)) = self.peek_op() {
+            if op.precedence() < min_precedence {
+                break;
+            }
+            self.rest = &self.rest[len..];
+            let right = self.binary(op.precedence() + 1)?;
+            left = Expr::Binary(op, Box::new(left), Box::new(right));
+        }
+        Ok(left)
+    }
+    fn unary(&mut self) -> Result<Expr, crate::Error> {
+        if self.eat("!") {
+            return Ok(Expr::Not(Box::new(self.nested(Self::unary)?)));
+        }
+        if self.eat("(") {
+            let inner = self.conditional()?;
+            if !self.eat(")") {
+                return Err(self.error("expected ')'"));
+            }
+            return Ok(inner);
+        }
+        if self.eat("n") {
+            return Ok(Expr::N);
+        }
+        let digits = self.rest.find(|c: char| !c.is_ascii_digit()).unwrap_or(self.rest.len());
+        if digits == 0 {
+            return Err(self.error("expected a number, 'n' or '('"));
+        }
+        let value = self.rest[..digits].parse().map_err(|_| self.error("number out of range"))?;
+        self.rest = &self.rest[digits..];
+        Ok(Expr::Number(value))
+    }
+}
+
+impl PluralRule {
+    pub fn parse(source: &str) -> Result<PluralRule, crate::Error> {
+        let source = source.trim().trim_end_matches(';');
+        if source.len() > MAX_PLURAL_LEN {
+            return Err(crate::Error::Parse(format!("Plural rule longer than {} bytes", MAX_PLURAL_LEN)));
+        }
+        let mut parser = PluralParser { source, rest: source, depth: 0 };
+        let expr = parser.conditional()?;
+        parser.skip_whitespace();
+        if !parser.rest.is_empty() {
+            return Err(parser.error(&format!("unexpected '{}'", parser.rest)));
+        }
+        Ok(PluralRule { expr })
+    }
+    /// Index of the plural form to use for the count `n`.
+    pub fn index(&self, n: u64) -> usize {
+        ::std::cmp::max(0, self.expr.eval(n as i64)) as usize
+    }
+}
+
+/// The germanic `n != 1`.
+impl Default for PluralRule {
+    fn default() -> Self {
+        PluralRule {
+            expr: Expr::Binary(Op::Ne, Box::new(Expr::N), Box::new(Expr::Number(1))),
+        }
+    }
+}
+
+/// Resolves the messages of the current locale, falling back to its language (`pt` for `pt_BR`),
+/// then to the fallback locale, and finally to the key itself.
+#[derive(Debug, Clone, Default)]
+pub struct Localizer {
+    catalogs: HashMap<String, Catalog>,
+    locale: Option<String>,
+    fallback: Option<String>,
+}
+
+impl Localizer {
+    pub fn new() -> Self {
+        Default::default()
+    }
+    /// Replaces the catalog of the same locale, if any.
+    pub fn add_catalog(&mut self, catalog: Catalog) {
+        self.catalogs.insert(catalog.locale.clone(), catalog);
+    }
+    pub fn catalog(&self, locale: &str) -> Option<&Catalog> {
+        self.catalogs.get(locale)
+    }
+    /// Loads every `<locale>.po` file of the directory.
+    pub fn load_dir<P: AsRef<Path>>(&mut self, dir: P) -> Result<(), crate::Error> {
+        let dir = dir.as_ref();
+        let entries = fs::read_dir(dir).map_err(|e| crate::Error::Io(format!("{}: {}", dir.display(), e)))?;
+        for entry in entries {
+            let path = entry.map_err(|e| crate::Error::Io(format!("{}: {}", dir.display(), e)))?.path();
+            if path.extension().is_none_or(|ext| ext != "po") {
+                continue;
+            }
+            if let Some(locale) = path.file_stem().and_then(|stem| stem.to_str()) {
+                let catalog = Catalog::load_po(locale, &path)?;
+                self.add_catalog(catalog);
+            }
+        }
+        Ok(())
+    }
+    pub fn locales(&self) -> impl Iterator<Item = &str> {
+        self.catalogs.keys().map(|locale| locale.as_str())
+    }
+    pub fn locale(&self) -> Option<&str> {
+        self.locale.as_deref()
+    }
+    /// Only switches the lookups, use `Application::set_locale` to also relabel the members.
+    pub fn set_locale<S: Into<String>>(&mut self, locale: Option<S>) {
+        self.locale = locale.map(|locale| locale.into());
+    }
+    pub fn fallback(&self) -> Option<&str> {
+        self.fallback.as_deref()
+    }
+    pub fn set_fallback<S: Into<String>>(&mut self, locale: Option<S>) {
+        self.fallback = locale.map(|locale| locale.into());
+    }
+
+    fn chain(&self) -> impl Iterator<Item = &Catalog> {
+        let locale = self.locale.as_deref();
+        let language = locale.and_then(|locale| locale.find(['_', '-']).map(|i| &locale[..i]));
+        locale.into_iter().chain(language).chain(self.fallback.as_deref()).filter_map(move |locale| self.catalogs.get(locale))
+    }
+    pub fn message<'a>(&'a self, key: &'a str) -> Cow<'a, str> {
+        self.chain().find_map(|catalog| catalog.message(key)).map_or(Cow::Borrowed(key), Cow::Borrowed)
+    }
+    pub fn message_plural<'a>(&'a self, key: &'a str, n: u64) -> Cow<'a, str> {
+        self.chain().find_map(|catalog| catalog.message_plural(key, n)).map_or(Cow::Borrowed(key), Cow::Borrowed)
+    }
+    pub fn format(&self, key: &str, args: Args) -> String {
+        substitute(&self.message(key), args)
+    }
+    /// Same as `format` with the plural form for `n`, which is also available as the `{n}` argument.
+    pub fn format_plural(&self, key: &str, n: u64, args: Args) -> String {
+        let mut all: Vec<(&str, &dyn fmt::Display)> = vec![("n", &n)];
+        all.extend_from_slice(args);
+        substitute(&self.message_plural(key, n), &all)
+    }
+}
+
+/// A message key with the plural count and the arguments to format it with. Members keep it
+/// to translate their labels again on every locale switch, see `HasLabel::set_label_key`.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Message {
+    key: String,
+    count: Option<u64>,
+    args: Vec<(String, String)>,
+}
+
+impl Message {
+    pub fn new<S: Into<String>>(key: S) -> Self {
+        Message { key: key.into(), ..Default::default() }
+    }
+    /// Picks the plural form for `n`, which is also available as the `{n}` argument.
+    pub fn with_count(mut self, n: u64) -> Self {
+        self.count = Some(n);
+        self
+    }
+    /// The value is formatted right away, only the message around it follows the locale.
+    pub fn with_arg<S: Into<String>, D: fmt::Display>(mut self, name: S, value: D) -> Self {
+        self.args.push((name.into(), value.to_string()));
+        self
+    }
+    pub fn key(&self) -> &str {
+        self.key.as_str()
+    }
+    pub fn count(&self) -> Option<u64> {
+        self.count
+    }
+    pub fn format(&self, localizer: &Localizer) -> String {
+        let args: Vec<(&str, &dyn fmt::Display)> = self.args.iter().map(|(name, value)| (name.as_str(), value as &dyn fmt::Display)).collect();
+        match self.count {
+            Some(n) => localizer.format_plural(&self.key, n, &args),
+            None => localizer.format(&self.key, &args),
+        }
+    }
+}
+impl<'a> From<&'a str> for Message {
+    fn from(key: &'a str) -> Self {
+        Message::new(key)
+    }
+}
+impl From<String> for Message {
+    fn from(key: String) -> Self {
+        Message::new(key)
+    }
+}
+
+/// Replaces the `{name}` placeholders of `message`. Unknown ones are kept as they are, `{{` and `}}` escape the braces.
+pub fn substitute(message: &str, args: Args) -> String {
+    let mut out = String::with_capacity(message.len());
+    let mut rest = message;
+    while let Some(start) = rest.find(['{', '}']) {
+        out.push_str(&rest[..start]);
+        let tail = &rest[start..];
+        if tail.starts_with("{{") || tail.starts_with("}}") {
+            out.push_str(&tail[..1]);
+            rest = &tail[2..];
+            continue;
+        }
+        match (tail.starts_with('{'), tail.find('}')) {
+            (true, Some(end)) => {
+                let name = &tail[1..end];
+                match args.iter().find(|(arg, _)| *arg == name) {
+                    Some((_, value)) => {
+                        let _ = write!(out, "{}", value);
+                    }
+                    None => out.push_str(&tail[..=end]),
+                }
+                rest = &tail[end + 1..];
+            }
+            _ => {
+                out.push_str(&tail[..1]);
+                rest = &tail[1..];
+            }
+        }
+    }
+    out.push_str(rest);
+    out
+}
+
+/// The `message` formatted in the current locale of the `Application`, or from its key itself without one.
+pub fn translate(message: &Message) -> String {
+    match crate::runtime::get_dyn() {
+        Some(app) => message.format(app.localizer()),
+        None => message.format(&Localizer::new()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const PO: &str = r#"
+msgid ""
+msgstr ""
+"Plural-Forms: nplurals=3; plural=n%10==1 && n%100!=11 ? 0 : n%10>=2 && n%10<=4 && (n%100<10 || n%100>=20) ? 1 : 2;\n"
+
+msgid "Hello, {name}!"
+msgstr "Привет, {name}!"
+
+#, fuzzy
+msgid "Skipped"
+msgstr "Пропущено"
+
+msgctxt "menu"
+msgid "Open"
+msgstr "Открыть"
+
+msgid "{n} file"
+msgid_plural "{n} files"
+msgstr[0] "{n} файл"
+msgstr[1] "{n} файла"
+msgstr[2] "{n} файлов"
+"#;
+
+    fn localizer() -> Localizer {
+        let mut localizer = Localizer::new();
+        localizer.add_catalog(Catalog::parse_po("ru_RU", PO).unwrap());
+        localizer.set_locale(Some("ru_RU"));
+        localizer
+    }
+
+    #[test]
+    fn parse_po_reads_messages_contexts_and_plurals() {
+        let catalog = Catalog::parse_po("ru", PO).unwrap();
+        assert_eq!(catalog.len(), 3);
+        assert_eq!(catalog.message("Hello, {name}!"), Some("Привет, {name}!"));
+        assert_eq!(catalog.message("Skipped"), None);
+        assert_eq!(catalog.message("menu\u{4}Open"), Some("Открыть"));
+        assert_eq!(catalog.message_plural("{n} file", 1), Some("{n} файл"));
+        assert_eq!(catalog.message_plural("{n} file", 3), Some("{n} файла"));
+        assert_eq!(catalog.message_plural("{n} file", 11), Some("{n} файлов"));
+        assert!(Catalog::parse_po("ru", "msgid \"a\"\nmsgwhat \"b\"").is_err());
+        assert!(Catalog::parse_po("ru", "msgid \"unterminated\nmsgstr \"\"").is_err());
+    }
+    #[test]
+    fn plural_rule_follows_c_precedence() {
+        let rule = PluralRule::parse("n==0 ? 0 : n==1 ? 1 : n%100>=3 && n%100<=10 ? 2 : 3;").unwrap();
+        assert_eq!([0, 1, 5, 99, 103].iter().map(|n| rule.index(*n)).collect::<Vec<_>>(), vec![0, 1, 2, 3, 2]);
+        assert_eq!(PluralRule::parse("!(n % 2)").unwrap().index(4), 1);
+        assert_eq!(PluralRule::parse("n / 0").unwrap().index(7), 0);
+        assert_eq!(PluralRule::default().index(1), 0);
+        assert_eq!(PluralRule::default().index(2), 1);
+        assert!(PluralRule::parse("n ==").is_err());
+        assert!(PluralRule::parse("(n").is_err());
+        assert!(PluralRule::parse("n ? 1").is_err());
+    }
+    #[test]
+    fn plural_rule_rejects_deep_nesting() {
+        assert!(PluralRule::parse(&format!("{}n{}", "(".repeat(8), ")".repeat(8))).is_ok());
+        assert!(PluralRule::parse(&format!("{}n{}", "(".repeat(100_000), ")".repeat(100_000))).is_err());
+        assert!(PluralRule::parse(&format!("{}n", "!".repeat(100_000))).is_err());
+        assert!(PluralRule::parse(&format!("{}n", "n ? 1 : ".repeat(4))).is_ok());
+        assert!(PluralRule::parse(&format!("{}n", "n ? 1 : ".repeat(100))).is_err());
+    }
+    #[test]
+    fn substitute_replaces_known_placeholders_only() {
+        assert_eq!(substitute("{a} and {b}", &[("a", &1), ("b", &"two")]), "1 and two");
+        assert_eq!(substitute("{missing} {a}", &[("a", &1)]), "{missing} 1");
+        assert_eq!(substitute("{{a}} {a}}", &[("a", &1)]), "{a} 1}");
+        assert_eq!(substitute("unclosed {a", &[("a", &1)]), "unclosed {a");
+    }
+    #[test]
+    fn message_keeps_count_and_args() {
+        let localizer = localizer();
+        let files = Message::new("{n} file").with_count(5);
+        assert_eq!(files.format(&localizer), "5 файлов");
+        assert_eq!(files.format(&Localizer::new()), "5 file");
+        assert_eq!(Message::new("Hello, {name}!").with_arg("name", "Аня").format(&localizer), "Привет, Аня!");
+        assert_eq!(Message::from("Unknown").format(&localizer), "Unknown");
+    }
+}
@@ -1,5 +1,10 @@
 use super::ids::Id;
+use super::types::Visibility;
 
+use std::fmt::{self, Write};
+
+/// All the layout values (sizes, coords, margins) are in logical pixels. Backends convert them
+/// to the physical pixels of the display with the scale factor of the window, see `Window::scale_factor`.
 #[derive(Debug, Clone, Copy, Eq, PartialEq)]
 pub enum Size {
     MatchParent,
@@ -24,12 +29,49 @@ pub enum Alignment {
     AlignParentBottom,
 }
 
+/// Horizontal flow of the content. Members inherit the one of their parent unless they set their own,
+/// see `Member::layout_direction`.
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash, Default)]
+pub enum Direction {
+    #[default]
+    Inherit,
+    LeftToRight,
+    RightToLeft,
+}
+impl Direction {
+    pub fn is_rtl(self) -> bool {
+        self == Direction::RightToLeft
+    }
+}
+/// Accepts `inherit`, `ltr` and `rtl`.
+impl ::std::str::FromStr for Direction {
+    type Err = crate::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.trim().to_ascii_lowercase().as_str() {
+            "inherit" => Ok(Direction::Inherit),
+            "ltr" => Ok(Direction::LeftToRight),
+            "rtl" => Ok(Direction::RightToLeft),
+            other => Err(crate::Error::Parse(format!("Invalid layout direction: '{}'", other))),
+        }
+    }
+}
+
 #[derive(Debug, Clone, Copy, Eq, PartialEq)]
 pub enum BoundarySize {
     AllTheSame(i32),
     OrthoDirections(i32, i32),
     Distinct(i32, i32, i32, i32),
 }
+impl BoundarySize {
+    /// Swaps the left and right sizes under `Direction::RightToLeft`.
+    pub fn for_direction(self, direction: Direction) -> BoundarySize {
+        match self {
+            BoundarySize::Distinct(l, t, r, b) if direction.is_rtl() => BoundarySize::Distinct(r, t, l, b),
+            other => other,
+        }
+    }
+}
 impl From<(i32, i32, i32, i32)> for BoundarySize {
     fn from(a: (i32, i32, i32, i32)) -> BoundarySize {
         let h = a.0 == a.2;
@@ -188,3 +230,195 @@ impl Default for Attributes {
         }
     }
 }
+
+#[inline]
+pub fn to_physical(logical: i32, scale_factor: f32) -> i32 {
+    (logical as f32 * scale_factor).round() as i32
+}
+#[inline]
+pub fn to_logical(physical: i32, scale_factor: f32) -> i32 {
+    (physical as f32 / scale_factor).round() as i32
+}
+
+/// Snapshot of a member and its children, as laid out at the moment, see `utils::dump_layout`.
+#[derive(Debug, Clone)]
+pub struct LayoutDump {
+    pub type_name: &'static str,
+    pub id: Id,
+    pub tag: Option<String>,
+    pub classes: Vec<String>,
+    /// `None` for the members not placed by a layout, like windows.
+    pub attributes: Option<Attributes>,
+    pub margin: Option<BoundarySize>,
+    pub measured: Option<(u16, u16)>,
+    pub coords: Option<(i32, i32)>,
+    pub visibility: Option<Visibility>,
+    pub children: Vec<LayoutDump>,
+}
+
+impl LayoutDump {
+    pub fn to_text(&self) -> String {
+        let mut out = String::new();
+        self.write_text(&mut out, 0);
+        out
+    }
+    pub fn to_json(&self) -> String {
+        let mut out = String::new();
+        self.write_json(&mut out);
+        out
+    }
+
+    fn write_text(&self, out: &mut String, depth: usize) {
+        for _ in 0..depth {
+            out.push_str("  ");
+        }
+        let _ = write!(out, "{} {:?}", self.type_name, self.id);
+        if let Some(ref tag) = self.tag {
+            let _ = write!(out, " '{}'", tag);
+        }
+        for class in self.classes.iter() {
+            let _ = write!(out, " .{}", class);
+        }
+        if let Some(ref attributes) = self.attributes {
+            let _ = write!(out, " width={:?} height={:?}", attributes.width, attributes.height);
+        }
+        if let Some(margin) = self.margin {
+            let (l, t, r, b) = margin.into();
+            let _ = write!(out, " margin=({}, {}, {}, {})", l, t, r, b);
+        }
+        if let Some((w, h)) = self.measured {
+            let _ = write!(out, " measured={}x{}", w, h);
+        }
+        if let Some((x, y)) = self.coords {
+            let _ = write!(out, " at ({}, {})", x, y);
+        }
+        if let Some(visibility) = self.visibility {
+            let _ = write!(out, " {:?}", visibility);
+        }
+        out.push('\n');
+        for child in self.children.iter() {
+            child.write_text(out, depth + 1);
+        }
+    }
+    fn write_json(&self, out: &mut String) {
+        let _ = write!(out, "{{\"type\":\"{}\",\"id\":{}", self.type_name, unsafe { self.id.into_raw() });
+        if let Some(ref tag) = self.tag {
+            out.push_str(",\"tag\":");
+            write_json_string(out, tag);
+        }
+        if !self.classes.is_empty() {
+            out.push_str(",\"classes\":[");
+            for (i, class) in self.classes.iter().enumerate() {
+                if i > 0 {
+                    out.push(',');
+                }
+                write_json_string(out, class);
+            }
+            out.push(']');
+        }
+        if let Some(ref attributes) = self.attributes {
+            out.push_str(",\"width\":");
+            write_json_size(out, attributes.width);
+            out.push_str(",\"height\":");
+            write_json_size(out, attributes.height);
+        }
+        if let Some(margin) = self.margin {
+            let (l, t, r, b) = margin.into();
+            let _ = write!(out, ",\"margin\":[{},{},{},{}]", l, t, r, b);
+        }
+        if let Some((w, h)) = self.measured {
+            let _ = write!(out, ",\"measured\":[{},{}]", w, h);
+        }
+        if let Some((x, y)) = self.coords {
+            let _ = write!(out, ",\"coords\":[{},{}]", x, y);
+        }
+        if let Some(visibility) = self.visibility {
+            let _ = write!(out, ",\"visibility\":\"{:?}\"", visibility);
+        }
+        out.push_str(",\"children\":[");
+        for (i, child) in self.children.iter().enumerate() {
+            if i > 0 {
+                out.push(',');
+            }
+            child.write_json(out);
+        }
+        out.push_str("]}");
+    }
+}
+impl fmt::Display for LayoutDump {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.to_text())
+    }
+}
+
+fn write_json_size(out: &mut String, size: Size) {
+    let _ = match size {
+        Size::Exact(value) => write!(out, "{}", value),
+        other => write!(out, "\"{:?}\"", other),
+    };
+}
+fn write_json_string(out: &mut String, value: &str) {
+    out.push('"');
+    for c in value.chars() {
+        match c {
+            '"' => out.push_str("\\\""),
+            '\\' => out.push_str("\\\\"),
+            '\n' => out.push_str("\\n"),
+            c if (c as u32) < 0x20 => {
+                let _ = write!(out, "\\u{:04x}", c as u32);
+            }
+            c => out.push(c),
+        }
+    }
+    out.push('"');
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn dump() -> LayoutDump {
+        let leaf = LayoutDump {
+            type_name: "Button",
+            id: unsafe { Id::from_raw(2) },
+            tag: Some("say \"hi\"\\\n\u{1}".into()),
+            classes: vec!["primary".into(), "wide".into()],
+            attributes: Some(Attributes { width: Size::Exact(40), height: Size::WrapContent }),
+            margin: Some(BoundarySize::Distinct(1, 2, 3, 4)),
+            measured: Some((40, 20)),
+            coords: Some((-5, 6)),
+            visibility: Some(Visibility::Gone),
+            children: vec![],
+        };
+        LayoutDump {
+            type_name: "Window",
+            id: unsafe { Id::from_raw(1) },
+            tag: None,
+            classes: vec![],
+            attributes: None,
+            margin: None,
+            measured: None,
+            coords: None,
+            visibility: None,
+            children: vec![leaf],
+        }
+    }
+
+    #[test]
+    fn to_json_writes_set_fields_and_escapes_strings() {
+        assert_eq!(
+            dump().to_json(),
+            concat!(
+                r#"{"type":"Window","id":1,"children":[{"type":"Button","id":2,"tag":"say \"hi\"\\\n\u0001","classes":["primary","wide"],"#,
+                r#""width":40,"height":"WrapContent","margin":[1,2,3,4],"measured":[40,20],"coords":[-5,6],"visibility":"Gone","children":[]}]}"#
+            )
+        );
+    }
+    #[cfg(feature = "markup")]
+    #[test]
+    fn to_json_is_valid_json() {
+        let value: serde_json::Value = serde_json::from_str(&dump().to_json()).unwrap();
+        assert_eq!(value["children"][0]["tag"], "say \"hi\"\\\n\u{1}");
+        assert_eq!(value["children"][0]["coords"][0], -5);
+    }
+}
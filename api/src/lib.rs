@@ -1,13 +1,17 @@
 #![warn(bare_trait_objects)]
 //#![cfg_attr(feature = "markup", feature(unboxed_closures))]
 #![feature(specialization)]
+#![cfg_attr(unix, feature(peer_credentials_unix_socket))]
 
 #[macro_use]
 extern crate plygui_macros;
 
+pub mod a11y;
 pub mod controls;
 pub mod defaults;
+pub mod error;
 pub mod ids;
+pub mod l10n;
 pub mod layout;
 pub mod types;
 pub mod utils;
@@ -16,6 +20,9 @@ pub(crate) mod inner;
 pub(crate) mod runtime;
 
 pub mod sdk;
+pub mod style;
+pub mod trace;
+pub mod traversal;
 
 #[macro_use]
 pub mod callbacks;
@@ -25,6 +32,9 @@ pub mod macros;
 #[cfg(feature = "markup")]
 pub mod markup;
 
+pub use error::Error;
+
 pub mod external {
     pub use image;
+    pub use tracing;
 }
@@ -16,6 +16,7 @@ macro_rules! default_pub_use {
             pub use crate::image::Image;
             pub use crate::layout_linear::LinearLayout;
             pub use crate::message::Message;
+            pub use crate::file_dialog::FileDialog;
             pub use crate::progress_bar::ProgressBar;
             pub use crate::splitted::Splitted;
             pub use crate::text::Text;
@@ -33,7 +34,7 @@ macro_rules! default_markup_register_members {
     () => {
         #[cfg(feature = "markup")]
         pub fn register_members(registry: &mut plygui_api::markup::MarkupRegistry) {
-            use plygui_api::development::Spawnable;
+            use plygui_api::sdk::Spawnable;
             
             registry.register_member(plygui_api::markup::MEMBER_TYPE_BUTTON.into(), imp::Button::spawn).unwrap();
             registry.register_member(plygui_api::markup::MEMBER_TYPE_LINEAR_LAYOUT.into(), imp::LinearLayout::spawn).unwrap();
@@ -21,6 +21,17 @@ where
 }
 
 pub const ID: &str = "id";
+/// Whitespace separated style classes, see `Member::add_class`.
+pub const CLASS: &str = "class";
+/// A `layout::Direction`: `inherit`, `ltr` or `rtl`.
+pub const DIRECTION: &str = "direction";
+/// A `types::Color`, as a hex value or a CSS name.
+pub const COLOR: &str = "color";
+pub const FONT_FAMILY: &str = "font_family";
+pub const FONT_SIZE: &str = "font_size";
+/// A `types::FontWeight`, by name or as a CSS numeric weight.
+pub const FONT_WEIGHT: &str = "font_weight";
+pub const FONT_ITALIC: &str = "font_italic";
 pub const TYPE: &str = "type";
 pub const CHILD: &str = "child";
 pub const CHILDREN: &str = "children";
@@ -119,7 +130,7 @@ impl MarkupRegistry {
     }
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq)]
 pub enum MarkupError {
     MemberNotFound,
     MemberAlreadyRegistered,
@@ -129,6 +140,19 @@ pub enum MarkupError {
     IdAlreadyExists,
 }
 
+impl fmt::Display for MarkupError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(match *self {
+            MarkupError::MemberNotFound => "no member is registered under this type",
+            MarkupError::MemberAlreadyRegistered => "a member is registered under this type already",
+            MarkupError::CallbackNotFound => "no callback is registered under this name",
+            MarkupError::CallbackAlreadyBinded => "a callback is registered under this name already",
+            MarkupError::IdNotFound => "no id is registered under this name",
+            MarkupError::IdAlreadyExists => "an id is registered under this name already",
+        })
+    }
+}
+
 #[derive(Debug, Clone)]
 pub struct Markup {
     pub id: Option<String>,
@@ -144,6 +168,24 @@ pub enum MarkupNode {
 }
 
 impl MarkupNode {
+    pub fn try_as_attribute(&self) -> Result<&str, crate::Error> {
+        match *self {
+            MarkupNode::Attribute(ref attr) => Ok(attr.as_str()),
+            _ => Err(crate::Error::MarkupSyntax(format!("MarkupNode is not an Attribute: {:?}", self))),
+        }
+    }
+    pub fn try_as_child(&self) -> Result<&Markup, crate::Error> {
+        match *self {
+            MarkupNode::Child(ref markup) => Ok(markup),
+            _ => Err(crate::Error::MarkupSyntax(format!("MarkupNode is not a Child Markup: {:?}", self))),
+        }
+    }
+    pub fn try_as_children(&self) -> Result<&[Markup], crate::Error> {
+        match *self {
+            MarkupNode::Children(ref children) => Ok(children.as_slice()),
+            _ => Err(crate::Error::MarkupSyntax(format!("MarkupNode is not the Children Markups: {:?}", self))),
+        }
+    }
     pub fn as_attribute(&self) -> &str {
         match *self {
             MarkupNode::Attribute(ref attr) => attr.as_str(),
@@ -193,7 +235,7 @@ impl<'de> Visitor<'de> for MarkupVisitor {
         let mut attributes = HashMap::new();
 
         while let Some(key) = map.next_key()? {
-            println!("{} found", key);
+            tracing::trace!(target: "plygui::markup", "{} found", key);
 
             match key {
                 TYPE => {
@@ -237,46 +279,110 @@ impl<'de> Visitor<'de> for MarkupVisitor {
     }
 }
 
-pub fn parse_markup(json: &str, registry: &mut MarkupRegistry) -> Box<dyn crate::controls::Control> {
-    let markup: Markup = serde_json::from_str(json).unwrap();
+pub fn parse_markup(json: &str, registry: &mut MarkupRegistry) -> Result<Box<dyn crate::controls::Control>, crate::Error> {
+    let markup: Markup = serde_json::from_str(json).map_err(|e| crate::Error::MarkupSyntax(e.to_string()))?;
 
-    let mut control = registry.member(&markup.member_type).unwrap()();
-    control.fill_from_markup(&markup, registry);
-    control
+    let mut control = registry.member(&markup.member_type)?();
+    control.fill_from_markup(&markup, registry)?;
+    Ok(control)
 }
 
+/// Pushes the callback `$cb`, e.g. an `OnClick`, to the registry under its variable name.
+/// Returns the error from the enclosing function if the name is taken already.
 #[macro_export]
 macro_rules! bind_markup_callback {
     ($reg: ident, $cb: ident) => {
-        registry.bind_callback(stringify!($cb), $cb as CallbackPtr).unwrap();
+        $reg.push_callback(stringify!($cb), $cb)?;
     };
 }
 #[macro_export]
 macro_rules! fill_from_markup_base {
 	($this: expr, $mem: expr, $mrk: ident, $reg: ident, $typ:ty, [$($arg:ident),+]) => {
 		if !&[$($arg),+].contains(&$mrk.member_type.as_str()) {
-			match $mrk.id {
-				Some(ref id) => panic!("Markup does not belong to {}: {} ({})", stringify!($typ), $mrk.member_type, id),
-				None => panic!("Markup does not belong to {}: {}", stringify!($typ), $mrk.member_type),
-			}
+			return Err(::plygui_api::Error::MarkupSyntax(match $mrk.id {
+				Some(ref id) => format!("Markup does not belong to {}: {} ({})", stringify!($typ), $mrk.member_type, id),
+				None => format!("Markup does not belong to {}: {}", stringify!($typ), $mrk.member_type),
+			}));
 		}
     	if let Some(ref id) = $mrk.id {
-    		$reg.store_id(&id, $mem.id()).unwrap();
+    		$reg.store_id(&id, $mem.id())?;
+    	}
+    	if let Some(classes) = $mrk.attributes.get(::plygui_api::markup::CLASS) {
+    		for class in classes.try_as_attribute()?.split_whitespace() {
+    			$mem.add_class(class.into());
+    		}
+    	}
+    	if let Some(direction) = $mrk.attributes.get(::plygui_api::markup::DIRECTION) {
+    		$mem.set_direction(direction.try_as_attribute()?.parse()?);
     	}
 	}
 }
 #[macro_export]
 macro_rules! fill_from_markup_label {
     ($this: expr, $mem: expr, $mrk: ident) => {
-        use plygui_api::development::HasLabelInner;
-        $this.set_label($mem, $mrk.attributes.get("label").unwrap().as_attribute().into());
+        use plygui_api::sdk::HasLabelInner;
+        let label = $mrk.attributes.get("label").ok_or_else(|| ::plygui_api::Error::MarkupSyntax("Missing attribute: label".into()))?;
+        let label = label.try_as_attribute()?;
+        // "@key" is a localization key, "@@" escapes a literal "@"
+        if label.starts_with("@@") {
+            $this.set_label($mem, label[1..].into());
+        } else if label.starts_with('@') {
+            let message = ::plygui_api::l10n::Message::new(&label[1..]);
+            $this.set_label($mem, ::plygui_api::l10n::translate(&message).into());
+            $mem.set_label_key(Some(message));
+        } else {
+            $this.set_label($mem, label.into());
+        }
+    };
+}
+#[macro_export]
+macro_rules! fill_from_markup_font {
+    ($this: expr, $mem: expr, $mrk: ident) => {
+        use plygui_api::sdk::HasFontInner;
+        use plygui_api::markup::{FONT_FAMILY, FONT_SIZE, FONT_WEIGHT, FONT_ITALIC};
+        if [FONT_FAMILY, FONT_SIZE, FONT_WEIGHT, FONT_ITALIC].iter().any(|key| $mrk.attributes.contains_key(*key)) {
+            let mut font = $mem.style().font.clone().unwrap_or_else(|| $this.font($mem).into_owned());
+            if let Some(family) = $mrk.attributes.get(FONT_FAMILY) {
+                font.family = family.try_as_attribute()?.into();
+            }
+            if let Some(size) = $mrk.attributes.get(FONT_SIZE) {
+                let size = size.try_as_attribute()?;
+                font.size = size.parse().map_err(|_| ::plygui_api::Error::MarkupSyntax(format!("Invalid font size: {}", size)))?;
+            }
+            if let Some(weight) = $mrk.attributes.get(FONT_WEIGHT) {
+                font.weight = weight.try_as_attribute()?.parse()?;
+            }
+            if let Some(italic) = $mrk.attributes.get(FONT_ITALIC) {
+                let italic = italic.try_as_attribute()?;
+                font.italic = italic.parse().map_err(|_| ::plygui_api::Error::MarkupSyntax(format!("Invalid font italic: {}", italic)))?;
+            }
+            // the own style keeps the font, see `style::Style::font`
+            let mut style = $mem.style().clone();
+            style.font = Some(font.clone());
+            $mem.set_style(style);
+            $this.set_font($mem, ::std::borrow::Cow::Owned(font));
+        }
+    };
+}
+#[macro_export]
+macro_rules! fill_from_markup_color {
+    ($this: expr, $mem: expr, $mrk: ident) => {
+        use plygui_api::sdk::HasColorInner;
+        if let Some(color) = $mrk.attributes.get(::plygui_api::markup::COLOR) {
+            let color = color.try_as_attribute()?.parse()?;
+            // the own style keeps the color, see `style::Style::foreground`
+            let mut style = $mem.style().clone();
+            style.foreground = Some(color);
+            $mem.set_style(style);
+            $this.set_color($mem, color);
+        }
     };
 }
 #[macro_export]
 macro_rules! fill_from_markup_callbacks {
 	($this: expr, $mrk: ident, $reg: ident, [$($cbname:ident => $cbtyp:ty),+]) => {
 		$(if let Some(callback) = $mrk.attributes.get(stringify!($cbname)) {
-    		let callback: $cbtyp = $reg.pop_callback(callback.as_attribute()).unwrap();
+    		let callback: $cbtyp = $reg.pop_callback(callback.try_as_attribute()?)?;
     		$this.$cbname(Some(callback));
     	})+
 	}
@@ -284,11 +390,11 @@ macro_rules! fill_from_markup_callbacks {
 #[macro_export]
 macro_rules! fill_from_markup_children {
     ($this: expr, $mem: expr, $mrk: ident, $reg: ident) => {
-        for child_markup in $mrk.attributes.get(::plygui_api::markup::CHILDREN).unwrap_or(&::plygui_api::markup::MarkupNode::Children(vec![])).as_children() {
-            use plygui_api::development::MultiContainerInner;
+        for child_markup in $mrk.attributes.get(::plygui_api::markup::CHILDREN).unwrap_or(&::plygui_api::markup::MarkupNode::Children(vec![])).try_as_children()? {
+            use plygui_api::sdk::MultiContainerInner;
 
-            let mut child = $reg.member(&child_markup.member_type).unwrap()();
-            child.fill_from_markup(child_markup, $reg);
+            let mut child = $reg.member(&child_markup.member_type)?();
+            child.fill_from_markup(child_markup, $reg)?;
             $this.push_child($mem, child);
         }
     };
@@ -296,11 +402,12 @@ macro_rules! fill_from_markup_children {
 #[macro_export]
 macro_rules! fill_from_markup_child {
     ($this: expr, $mem: expr, $mrk: ident, $reg: ident) => {
-        if let Some(child_markup) = $mrk.attributes.get(::plygui_api::markup::CHILD).map(|m| m.as_child()) {
-            use plygui_api::development::SingleContainerInner;
+        if let Some(child_markup) = $mrk.attributes.get(::plygui_api::markup::CHILD) {
+            use plygui_api::sdk::SingleContainerInner;
 
-            let mut child = $reg.member(&child_markup.member_type).unwrap()();
-            child.fill_from_markup(child_markup, $reg);
+            let child_markup = child_markup.try_as_child()?;
+            let mut child = $reg.member(&child_markup.member_type)?();
+            child.fill_from_markup(child_markup, $reg)?;
             $this.set_child($mem, Some(child));
         }
     };
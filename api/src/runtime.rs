@@ -1,49 +1,138 @@
+use crate::ids;
 use crate::inner::application::Application;
-use std::cell::RefCell;
+use crate::types::ApplicationResult;
 
-static mut READY: bool = false;
+use std::cell::{Cell, RefCell};
+use std::mem;
+use std::sync::Mutex;
+use std::thread::{self, ThreadId};
+
+/// The UI thread currently owning the Application, if any.
+static OWNER: Mutex<Option<ThreadId>> = Mutex::new(None);
 
 thread_local! {
-    pub static APPLICATION: RefCell<usize> = RefCell::new(0);
+    static APPLICATION: Cell<Option<*mut dyn Application>> = Cell::new(None);
+    static LAYOUT_QUEUE: RefCell<Vec<ids::Id>> = const { RefCell::new(Vec::new()) };
+    static ADDING: Cell<usize> = const { Cell::new(0) };
+}
+
+pub fn get_dyn() -> Option<&'static mut dyn Application> {
+    APPLICATION.with(|a| a.get()).map(|a| unsafe { &mut *a })
 }
 
 pub fn get<T: Application>() -> Option<&'static mut T> {
-    let ready = unsafe { READY };
-    if ready {
-        APPLICATION.with(|a| {
-            let a = *a.borrow() as *mut T;
-            if a.is_null() {
-                panic!("Trying to access Application from a non-UI thread")
-            } else {
-                Some(unsafe { &mut *a })
-            }
-        })
-    } else {
-        None
-    }
-}
-
-pub fn init<T: Application>(app: &mut T) {
-    if unsafe { READY } {
-        panic!("Already initialized!") 
-    } else {
-        // TODO here may come the race!
-        APPLICATION.with(|a| {
-            *a.borrow_mut() = app as *mut _ as usize;
-        });
-        unsafe {
-            READY = true;
+    get_dyn().and_then(|a| a.as_any_mut().downcast_mut::<T>())
+}
+
+/// Claim of the runtime by the current thread, see `reserve`.
+/// Dropped without `init`, e.g. when creating the Application panics, it releases the runtime again.
+#[must_use]
+pub struct Reservation {
+    _private: (),
+}
+
+impl Reservation {
+    /// Registers the Application created after the runtime got reserved.
+    pub fn init<T: Application>(self, app: &mut T) {
+        APPLICATION.with(|a| a.set(Some(app as &mut dyn Application as *mut dyn Application)));
+        mem::forget(self);
+    }
+}
+
+impl Drop for Reservation {
+    fn drop(&mut self) {
+        deinit();
+    }
+}
+
+/// Claims the runtime for the current thread, before the Application gets created.
+pub fn reserve() -> Result<Reservation, ApplicationResult> {
+    let mut owner = OWNER.lock().unwrap_or_else(|e| e.into_inner());
+    let current = thread::current().id();
+    match *owner {
+        Some(id) if id == current => Err(ApplicationResult::ErrorAlreadyInitialized),
+        Some(_) => Err(ApplicationResult::ErrorNonUiThread),
+        None => {
+            *owner = Some(current);
+            Ok(Reservation { _private: () })
         }
     }
 }
+
+/// Releases the runtime, so a new Application can be created afterwards. No-op outside of the UI thread.
 pub fn deinit() {
-    if unsafe { READY } {
-        // TODO here may come the race!
-        APPLICATION.with(|a| {
-            *a.borrow_mut() = 0;
-        });
-        unsafe {
-            READY = false;
+    let mut owner = OWNER.lock().unwrap_or_else(|e| e.into_inner());
+    if *owner == Some(thread::current().id()) {
+        APPLICATION.with(|a| a.set(None));
+        LAYOUT_QUEUE.with(|q| q.borrow_mut().clear());
+        *owner = None;
+    }
+}
+
+/// Queues the topmost control with the given id for the next layout pass.
+pub fn queue_layout(id: ids::Id) {
+    LAYOUT_QUEUE.with(|q| {
+        let mut q = q.borrow_mut();
+        if !q.contains(&id) {
+            q.push(id);
         }
+    });
+}
+pub fn layout_queue() -> Vec<ids::Id> {
+    LAYOUT_QUEUE.with(|q| q.borrow().clone())
+}
+pub fn take_layout_queue() -> Vec<ids::Id> {
+    LAYOUT_QUEUE.with(|q| ::std::mem::take(&mut *q.borrow_mut()))
+}
+
+/// A control being added to a container, see `begin_adding`.
+pub struct Adding {
+    outermost: bool,
+}
+impl Adding {
+    /// `false` while an ancestor is being added too, which then takes care of the whole subtree.
+    pub fn is_outermost(&self) -> bool {
+        self.outermost
+    }
+}
+impl Drop for Adding {
+    fn drop(&mut self) {
+        ADDING.with(|a| a.set(a.get() - 1));
+    }
+}
+/// Marks a control as being added to a container until the returned guard is dropped.
+/// Containers add their children from within their own `on_added_to_container`.
+pub fn begin_adding() -> Adding {
+    let depth = ADDING.with(|a| a.replace(a.get() + 1));
+    Adding { outermost: depth == 0 }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn dropped_reservation_releases_the_runtime() {
+        let reservation = reserve().ok().unwrap();
+        assert!(matches!(reserve(), Err(ApplicationResult::ErrorAlreadyInitialized)));
+        let panicked = ::std::panic::catch_unwind(::std::panic::AssertUnwindSafe(move || {
+            let _reservation = reservation;
+            panic!("creating the application failed");
+        }));
+        assert!(panicked.is_err());
+        drop(reserve().ok().unwrap());
+    }
+
+
+    #[test]
+    fn queued_layouts_are_coalesced() {
+        take_layout_queue();
+        let (first, second) = (ids::Id::next(), ids::Id::next());
+        queue_layout(first);
+        queue_layout(second);
+        queue_layout(first);
+        assert_eq!(layout_queue(), vec![first, second]);
+        assert_eq!(take_layout_queue(), vec![first, second]);
+        assert!(layout_queue().is_empty());
     }
 }
@@ -1,6 +1,9 @@
 pub use crate::inner::{
     auto::{Spawnable, HasInner, Abstract},
     application::{AApplication, ApplicationBase, ApplicationInner, NewApplicationInner},
+    timer::Timers,
+    executor::LocalExecutor,
+    instance::{InstanceRole, SingleInstance},
     member::{AMember, MemberBase, MemberInner},
     button::{AButton, ButtonInner, NewButtonInner},
     text::{AText, TextInner, NewTextInner},
@@ -14,6 +17,8 @@ pub use crate::inner::{
     closeable::{CloseableInner, ACloseable},
     clickable::ClickableInner,
     item_clickable::ItemClickableInner,
+    has_color::HasColorInner,
+    has_font::HasFontInner,
     has_label::HasLabelInner,
     has_layout::HasLayoutInner,
     has_image::HasImageInner,
@@ -23,6 +28,7 @@ pub use crate::inner::{
     has_orientation::HasOrientationInner,
     window::{AWindow, WindowBase, WindowInner, NewWindowInner},
     message::{AMessage, MessageInner},
+    file_dialog::{AFileDialog, FileDialogInner},
     image::{AImage, ImageInner, NewImageInner},
     frame::{AFrame, FrameInner, NewFrameInner},
     layout_linear::{ALinearLayout, LinearLayoutInner, NewLinearLayoutInner},
@@ -0,0 +1,164 @@
+//! Colors, fonts and spacing of the members, set through a `Stylesheet` of selector keyed rules.
+//!
+//! The style of a member is resolved from the rules matching it, ordered by their selector specificity
+//! (and by their order in the stylesheet between equal ones), then from its own `Member::style`.
+//! The foreground color and the font left unset are inherited from the parent, the background, padding and border are not.
+//! The foreground and the font are the `HasColor` color and the `HasFont` font: setting those sets them in the own style,
+//! and the resolved ones are handed to the backend.
+//! Rules can be limited to a color scheme, and then win over the unlimited ones of the same specificity.
+
+use crate::controls::Member;
+use crate::layout::BoundarySize;
+use crate::traversal::{Ancestors, DepthFirstMut};
+use crate::types::{Color, ColorScheme, Font, Selector};
+
+/// Every property is optional, so styles can be layered on top of each other.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Style {
+    /// The `HasColor` color of the member.
+    pub foreground: Option<Color>,
+    pub background: Option<Color>,
+    /// The `HasFont` font of the member, set and inherited as a whole.
+    pub font: Option<Font>,
+    pub padding: Option<BoundarySize>,
+    pub border: Option<Border>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Border {
+    pub width: u16,
+    pub color: Color,
+}
+
+impl Style {
+    pub fn new() -> Self {
+        Default::default()
+    }
+    pub fn is_empty(&self) -> bool {
+        *self == Style::default()
+    }
+    /// Overrides the properties of `self` with the ones set in `other`.
+    pub fn merge(&mut self, other: &Style) {
+        if other.foreground.is_some() {
+            self.foreground = other.foreground;
+        }
+        if other.background.is_some() {
+            self.background = other.background;
+        }
+        if other.font.is_some() {
+            self.font = other.font.clone();
+        }
+        if other.padding.is_some() {
+            self.padding = other.padding;
+        }
+        if other.border.is_some() {
+            self.border = other.border;
+        }
+    }
+    /// Fills the unset inherited properties (foreground and font) from the parent style.
+    pub fn inherit(&mut self, parent: &Style) {
+        if self.foreground.is_none() {
+            self.foreground = parent.foreground;
+        }
+        if self.font.is_none() {
+            self.font = parent.font.clone();
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Rule {
+    pub selector: Selector,
+    pub style: Style,
+    /// Only applies under this color scheme, `None` applies under all of them.
+    pub scheme: Option<ColorScheme>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Stylesheet {
+    rules: Vec<Rule>,
+    scheme: ColorScheme,
+}
+
+impl Stylesheet {
+    pub fn new() -> Self {
+        Default::default()
+    }
+    pub fn try_with_rule(mut self, selector: &str, style: Style) -> Result<Self, crate::Error> {
+        self.add_rule(Selector::parse(selector)?, style);
+        Ok(self)
+    }
+    pub fn with_rule(self, selector: &str, style: Style) -> Self {
+        self.try_with_rule(selector, style).unwrap()
+    }
+    pub fn try_with_scheme_rule(mut self, scheme: ColorScheme, selector: &str, style: Style) -> Result<Self, crate::Error> {
+        self.add_scheme_rule(scheme, Selector::parse(selector)?, style);
+        Ok(self)
+    }
+    pub fn with_scheme_rule(self, scheme: ColorScheme, selector: &str, style: Style) -> Self {
+        self.try_with_scheme_rule(scheme, selector, style).unwrap()
+    }
+    pub fn add_rule(&mut self, selector: Selector, style: Style) {
+        self.rules.push(Rule { selector, style, scheme: None });
+    }
+    pub fn add_scheme_rule(&mut self, scheme: ColorScheme, selector: Selector, style: Style) {
+        self.rules.push(Rule { selector, style, scheme: Some(scheme) });
+    }
+    /// The color scheme the rules are resolved for. The `Application` keeps it in sync with the system one.
+    pub fn scheme(&self) -> ColorScheme {
+        self.scheme
+    }
+    pub fn set_scheme(&mut self, scheme: ColorScheme) {
+        self.scheme = scheme;
+    }
+    pub fn rules(&self) -> &[Rule] {
+        self.rules.as_slice()
+    }
+    pub fn is_empty(&self) -> bool {
+        self.rules.is_empty()
+    }
+    /// The style of `member`, given the resolved style of its parent.
+    pub fn resolve(&self, member: &dyn Member, parent: Option<&Style>) -> Style {
+        let mut matching: Vec<&Rule> = self.rules.iter().filter(|rule| rule.scheme.is_none_or(|scheme| scheme == self.scheme) && rule.selector.matches(member)).collect();
+        matching.sort_by_key(|rule| (rule.selector.specificity(), rule.scheme.is_some()));
+        let mut style = Style::default();
+        for rule in matching {
+            style.merge(&rule.style);
+        }
+        style.merge(member.style());
+        if let Some(parent) = parent {
+            style.inherit(parent);
+        }
+        style
+    }
+}
+
+/// Resolves the style of `member` and of all its descendants, handing the changed ones to their backends
+/// through `OuterControl::on_style_changed`.
+pub fn restyle(sheet: &Stylesheet, member: &mut dyn Member) {
+    let mut inherited = None;
+    if let Some(control) = member.is_control() {
+        let ancestors: Vec<&dyn Member> = Ancestors::new(control).collect();
+        for ancestor in ancestors.into_iter().rev() {
+            inherited = Some(sheet.resolve(ancestor, inherited.as_ref()));
+        }
+    }
+    restyle_with_parent(sheet, member, inherited.as_ref());
+}
+
+/// Same as `restyle`, but takes the resolved style of the parent instead of resolving all the ancestors again,
+/// e.g. its `Member::computed_style` when adding `member` to it.
+pub fn restyle_with_parent(sheet: &Stylesheet, member: &mut dyn Member, inherited: Option<&Style>) {
+    // resolved styles along the current path, pre-order visits parents right before their children
+    let mut path: Vec<Style> = Vec::new();
+    let mut walk = DepthFirstMut::new(member);
+    while let Some(node) = walk.next() {
+        path.truncate(node.depth());
+        let style = sheet.resolve(node.member, path.last().or(inherited));
+        path.push(style.clone());
+        match node.member.is_control_mut() {
+            Some(control) => control.on_style_changed(style),
+            None => node.member.as_base_mut().set_computed_style(style),
+        }
+    }
+}
@@ -0,0 +1,171 @@
+//! Instrumentation based on the `tracing` facade.
+//!
+//! Measure and draw passes are spans, callback invocations and adapter changes are events,
+//! all carrying the `id` and `tag` of their member. Install any `tracing` subscriber to collect them,
+//! and use `set_filter` to only trace the members of interest.
+
+use crate::callbacks::Callback;
+use crate::ids;
+use crate::inner::member::MemberBase;
+use crate::types::adapter::Change;
+
+use std::cell::RefCell;
+use std::fmt;
+
+pub use tracing::Span;
+use tracing::Level;
+
+/// Members to trace. An empty filter traces everything.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Filter {
+    ids: Vec<ids::Id>,
+    tags: Vec<String>,
+}
+
+impl Filter {
+    pub fn new() -> Self {
+        Default::default()
+    }
+    pub fn with_id(mut self, id: ids::Id) -> Self {
+        self.ids.push(id);
+        self
+    }
+    pub fn with_tag<S: Into<String>>(mut self, tag: S) -> Self {
+        self.tags.push(tag.into());
+        self
+    }
+    pub fn is_empty(&self) -> bool {
+        self.ids.is_empty() && self.tags.is_empty()
+    }
+    pub fn matches(&self, id: ids::Id, tag: Option<&str>) -> bool {
+        self.is_empty() || self.ids.contains(&id) || tag.is_some_and(|tag| self.tags.iter().any(|t| t == tag))
+    }
+}
+
+thread_local! {
+    static FILTER: RefCell<Filter> = RefCell::new(Filter::new());
+}
+
+/// Sets the filter of the current (UI) thread.
+pub fn set_filter(filter: Filter) {
+    FILTER.with(|f| *f.borrow_mut() = filter);
+}
+pub fn filter() -> Filter {
+    FILTER.with(|f| f.borrow().clone())
+}
+/// Callers check `tracing::enabled!` first, so the filter is not consulted in every pass when nobody listens.
+pub fn is_traced(base: &MemberBase) -> bool {
+    FILTER.with(|f| {
+        let filter = f.borrow();
+        // the tag is only looked up if the filter has tags
+        filter.is_empty() || filter.ids.contains(&base.id()) || (!filter.tags.is_empty() && filter.matches(base.id(), base.tag().as_deref()))
+    })
+}
+
+pub fn measure(base: &MemberBase, w: u16, h: u16) -> Span {
+    if !tracing::enabled!(target: "plygui::layout", Level::TRACE) || !is_traced(base) {
+        return Span::none();
+    }
+    tracing::trace_span!(target: "plygui::layout", "measure", id = ?base.id(), tag = ?base.tag(), w, h)
+}
+pub fn draw(base: &MemberBase, coords: Option<(i32, i32)>) -> Span {
+    if !tracing::enabled!(target: "plygui::layout", Level::TRACE) || !is_traced(base) {
+        return Span::none();
+    }
+    tracing::trace_span!(target: "plygui::layout", "draw", id = ?base.id(), tag = ?base.tag(), coords = ?coords)
+}
+/// Records the invocation of `cb`. Callbacks not bound to a member, like the `Application` ones, pass `None`.
+pub fn callback<C: Callback>(base: Option<&MemberBase>, cb: &C) {
+    if !tracing::enabled!(target: "plygui::callback", Level::TRACE) {
+        return;
+    }
+    match base {
+        Some(base) if is_traced(base) => tracing::trace!(target: "plygui::callback", id = ?base.id(), tag = ?base.tag(), callback = cb.name(), callback_id = %cb.id()),
+        Some(_) => {}
+        None => tracing::trace!(target: "plygui::callback", callback = cb.name(), callback_id = %cb.id()),
+    }
+}
+pub fn adapter_change(base: &MemberBase, change: &Change) {
+    if tracing::enabled!(target: "plygui::adapter", Level::DEBUG) && is_traced(base) {
+        tracing::debug!(target: "plygui::adapter", id = ?base.id(), tag = ?base.tag(), change = ?change);
+    }
+}
+/// Free form diagnostics of a backend about a member.
+pub fn backend(base: &MemberBase, message: fmt::Arguments) {
+    if tracing::enabled!(target: "plygui::backend", Level::DEBUG) && is_traced(base) {
+        tracing::debug!(target: "plygui::backend", id = ?base.id(), tag = ?base.tag(), "{}", message);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::callbacks::CallbackId;
+
+    use std::sync::{Arc, Mutex};
+    use tracing::field::{Field, Visit};
+    use tracing::span::{Attributes, Id, Record};
+    use tracing::{Event, Metadata, Subscriber};
+
+    struct Named(CallbackId);
+    impl Callback for Named {
+        fn name(&self) -> &'static str {
+            "Named"
+        }
+        fn id(&self) -> CallbackId {
+            self.0
+        }
+    }
+
+    /// Keeps the target and the `callback` field of every event.
+    struct Recorder(Arc<Mutex<Vec<String>>>);
+    struct CallbackField<'a>(&'a str, &'a mut Option<String>);
+    impl Visit for CallbackField<'_> {
+        fn record_str(&mut self, field: &Field, value: &str) {
+            if field.name() == "callback" {
+                *self.1 = Some(format!("{} {}", self.0, value));
+            }
+        }
+        fn record_debug(&mut self, _: &Field, _: &dyn fmt::Debug) {}
+    }
+    impl Subscriber for Recorder {
+        fn enabled(&self, _: &Metadata<'_>) -> bool {
+            true
+        }
+        fn new_span(&self, _: &Attributes<'_>) -> Id {
+            Id::from_u64(1)
+        }
+        fn record(&self, _: &Id, _: &Record<'_>) {}
+        fn record_follows_from(&self, _: &Id, _: &Id) {}
+        fn event(&self, event: &Event<'_>) {
+            let mut recorded = None;
+            event.record(&mut CallbackField(event.metadata().target(), &mut recorded));
+            self.0.lock().unwrap().extend(recorded);
+        }
+        fn enter(&self, _: &Id) {}
+        fn exit(&self, _: &Id) {}
+    }
+
+    #[test]
+    fn events_reach_an_installed_subscriber() {
+        let events = Arc::new(Mutex::new(Vec::new()));
+        tracing::subscriber::with_default(Recorder(events.clone()), || {
+            assert!(tracing::enabled!(target: "plygui::callback", Level::TRACE));
+            callback(None, &Named(CallbackId::next()));
+        });
+        assert_eq!(*events.lock().unwrap(), vec!["plygui::callback Named"]);
+    }
+    #[test]
+    fn nothing_is_emitted_without_a_subscriber() {
+        // every helper returns before building its span or event when these are off
+        assert!(!tracing::enabled!(target: "plygui::layout", Level::TRACE));
+        assert!(!tracing::enabled!(target: "plygui::callback", Level::TRACE));
+        assert!(!tracing::enabled!(target: "plygui::adapter", Level::DEBUG));
+        assert!(!tracing::enabled!(target: "plygui::backend", Level::DEBUG));
+        callback(None, &Named(CallbackId::next()));
+        // a subscriber installed afterwards gets nothing from before
+        let events = Arc::new(Mutex::new(Vec::new()));
+        tracing::subscriber::with_default(Recorder(events.clone()), || {});
+        assert!(events.lock().unwrap().is_empty());
+    }
+}
@@ -0,0 +1,264 @@
+//! Generic walks over the member trees, for tooling that has to visit every control.
+//!
+//! Each visited member comes with its path: the child indices leading to it from where the walk started.
+//! Walks over `Application::roots()` start the path with the index of the root.
+//!
+//! The mutable walks lend out one member at a time: `next` borrows the walk until the visited member is dropped,
+//! so a parent and its child can never be reached mutably at once. Use `while let Some(node) = walk.next()`
+//! or `for_each_mut` instead of a `for` loop.
+
+use crate::controls::{Container, Control, Member};
+
+use std::collections::VecDeque;
+
+pub struct Node<'a> {
+    pub path: Vec<usize>,
+    pub member: &'a dyn Member,
+}
+pub struct NodeMut<'a> {
+    pub path: Vec<usize>,
+    pub member: &'a mut dyn Member,
+}
+impl<'a> Node<'a> {
+    pub fn depth(&self) -> usize {
+        self.path.len()
+    }
+}
+impl<'a> NodeMut<'a> {
+    pub fn depth(&self) -> usize {
+        self.path.len()
+    }
+}
+
+/// Direct children of a container, in order. Empty for anything else.
+pub fn children(member: &dyn Member) -> Vec<&dyn Member> {
+    let mut children = Vec::new();
+    if let Some(container) = member.is_container() {
+        if let Some(multi) = container.is_multi_container() {
+            for i in 0..multi.len() {
+                if let Some(child) = multi.child_at(i) {
+                    children.push(child.as_member());
+                }
+            }
+        } else if let Some(child) = container.is_single_container().and_then(|single| single.child()) {
+            children.push(child.as_member());
+        }
+    }
+    children
+}
+fn children_mut(member: &mut dyn Member) -> Vec<*mut dyn Member> {
+    let mut children = Vec::new();
+    if let Some(container) = member.is_container_mut() {
+        if let Some(multi) = container.is_multi_container_mut() {
+            for i in 0..multi.len() {
+                if let Some(child) = multi.child_at_mut(i) {
+                    children.push(child.as_member_mut() as *mut dyn Member);
+                }
+            }
+        } else if let Some(child) = container.is_single_container_mut().and_then(|single| single.child_mut()) {
+            children.push(child.as_member_mut() as *mut dyn Member);
+        }
+    }
+    children
+}
+fn child_path(path: &[usize], index: usize) -> Vec<usize> {
+    let mut path = path.to_vec();
+    path.push(index);
+    path
+}
+
+/// Pre-order depth-first walk.
+pub struct DepthFirst<'a> {
+    stack: Vec<Node<'a>>,
+}
+impl<'a> DepthFirst<'a> {
+    /// Visits `root` itself (with an empty path) and all its descendants.
+    pub fn new(root: &'a dyn Member) -> Self {
+        DepthFirst { stack: vec![Node { path: Vec::new(), member: root }] }
+    }
+    /// Visits the descendants of `container`, but not the container itself.
+    pub fn descendants(container: &'a dyn Container) -> Self {
+        let mut stack: Vec<Node<'a>> = children(container.as_member()).into_iter().enumerate().map(|(i, member)| Node { path: vec![i], member }).collect();
+        stack.reverse();
+        DepthFirst { stack }
+    }
+    pub fn from_roots<I: Iterator<Item = &'a dyn Member>>(roots: I) -> Self {
+        let mut stack: Vec<Node<'a>> = roots.enumerate().map(|(i, member)| Node { path: vec![i], member }).collect();
+        stack.reverse();
+        DepthFirst { stack }
+    }
+}
+impl<'a> Iterator for DepthFirst<'a> {
+    type Item = Node<'a>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let node = self.stack.pop()?;
+        let children = children(node.member);
+        for (i, child) in children.into_iter().enumerate().rev() {
+            self.stack.push(Node { path: child_path(&node.path, i), member: child });
+        }
+        Some(node)
+    }
+}
+
+/// Level-order breadth-first walk.
+pub struct BreadthFirst<'a> {
+    queue: VecDeque<Node<'a>>,
+}
+impl<'a> BreadthFirst<'a> {
+    /// Visits `root` itself (with an empty path) and all its descendants.
+    pub fn new(root: &'a dyn Member) -> Self {
+        let mut queue = VecDeque::new();
+        queue.push_back(Node { path: Vec::new(), member: root });
+        BreadthFirst { queue }
+    }
+    /// Visits the descendants of `container`, but not the container itself.
+    pub fn descendants(container: &'a dyn Container) -> Self {
+        BreadthFirst {
+            queue: children(container.as_member()).into_iter().enumerate().map(|(i, member)| Node { path: vec![i], member }).collect(),
+        }
+    }
+    pub fn from_roots<I: Iterator<Item = &'a dyn Member>>(roots: I) -> Self {
+        BreadthFirst {
+            queue: roots.enumerate().map(|(i, member)| Node { path: vec![i], member }).collect(),
+        }
+    }
+}
+impl<'a> Iterator for BreadthFirst<'a> {
+    type Item = Node<'a>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let node = self.queue.pop_front()?;
+        for (i, child) in children(node.member).into_iter().enumerate() {
+            self.queue.push_back(Node { path: child_path(&node.path, i), member: child });
+        }
+        Some(node)
+    }
+}
+
+/// Mutable pre-order depth-first walk. The children of a member are looked up only after it was visited,
+/// so adding or removing them from within the walk is reflected.
+pub struct DepthFirstMut<'a> {
+    stack: Vec<(Vec<usize>, *mut dyn Member)>,
+    last: Option<(Vec<usize>, *mut dyn Member)>,
+    _marker: ::std::marker::PhantomData<&'a mut dyn Member>,
+}
+impl<'a> DepthFirstMut<'a> {
+    pub fn new(root: &'a mut dyn Member) -> Self {
+        DepthFirstMut { stack: vec![(Vec::new(), root as *mut dyn Member)], last: None, _marker: ::std::marker::PhantomData }
+    }
+    pub fn descendants(container: &'a mut dyn Container) -> Self {
+        let mut stack: Vec<(Vec<usize>, *mut dyn Member)> = children_mut(container.as_member_mut()).into_iter().enumerate().map(|(i, member)| (vec![i], member)).collect();
+        stack.reverse();
+        DepthFirstMut { stack, last: None, _marker: ::std::marker::PhantomData }
+    }
+    pub fn from_roots<I: Iterator<Item = &'a mut dyn Member>>(roots: I) -> Self {
+        let mut stack: Vec<(Vec<usize>, *mut dyn Member)> = roots.enumerate().map(|(i, member)| (vec![i], member as *mut dyn Member)).collect();
+        stack.reverse();
+        DepthFirstMut { stack, last: None, _marker: ::std::marker::PhantomData }
+    }
+    /// The next member, borrowed from the walk until it is dropped.
+    #[allow(clippy::should_implement_trait)]
+    pub fn next(&mut self) -> Option<NodeMut<'_>> {
+        if let Some((path, member)) = self.last.take() {
+            for (i, child) in children_mut(unsafe { &mut *member }).into_iter().enumerate().rev() {
+                self.stack.push((child_path(&path, i), child));
+            }
+        }
+        let (path, member) = self.stack.pop()?;
+        self.last = Some((path.clone(), member));
+        Some(NodeMut { path, member: unsafe { &mut *member } })
+    }
+    pub fn for_each_mut<F: FnMut(NodeMut<'_>)>(mut self, mut f: F) {
+        while let Some(node) = self.next() {
+            f(node);
+        }
+    }
+}
+
+/// Mutable level-order breadth-first walk, lending out the members like `DepthFirstMut`.
+pub struct BreadthFirstMut<'a> {
+    queue: VecDeque<(Vec<usize>, *mut dyn Member)>,
+    last: Option<(Vec<usize>, *mut dyn Member)>,
+    _marker: ::std::marker::PhantomData<&'a mut dyn Member>,
+}
+impl<'a> BreadthFirstMut<'a> {
+    pub fn new(root: &'a mut dyn Member) -> Self {
+        let mut queue = VecDeque::new();
+        queue.push_back((Vec::new(), root as *mut dyn Member));
+        BreadthFirstMut { queue, last: None, _marker: ::std::marker::PhantomData }
+    }
+    pub fn descendants(container: &'a mut dyn Container) -> Self {
+        BreadthFirstMut {
+            queue: children_mut(container.as_member_mut()).into_iter().enumerate().map(|(i, member)| (vec![i], member)).collect(),
+            last: None,
+            _marker: ::std::marker::PhantomData,
+        }
+    }
+    pub fn from_roots<I: Iterator<Item = &'a mut dyn Member>>(roots: I) -> Self {
+        BreadthFirstMut {
+            queue: roots.enumerate().map(|(i, member)| (vec![i], member as *mut dyn Member)).collect(),
+            last: None,
+            _marker: ::std::marker::PhantomData,
+        }
+    }
+    /// The next member, borrowed from the walk until it is dropped.
+    #[allow(clippy::should_implement_trait)]
+    pub fn next(&mut self) -> Option<NodeMut<'_>> {
+        if let Some((path, member)) = self.last.take() {
+            for (i, child) in children_mut(unsafe { &mut *member }).into_iter().enumerate() {
+                self.queue.push_back((child_path(&path, i), child));
+            }
+        }
+        let (path, member) = self.queue.pop_front()?;
+        self.last = Some((path.clone(), member));
+        Some(NodeMut { path, member: unsafe { &mut *member } })
+    }
+    pub fn for_each_mut<F: FnMut(NodeMut<'_>)>(mut self, mut f: F) {
+        while let Some(node) = self.next() {
+            f(node);
+        }
+    }
+}
+
+/// Walks up from a control through its parents, the last one being its root (e.g. a window).
+pub struct Ancestors<'a> {
+    next: Option<&'a dyn Member>,
+}
+impl<'a> Ancestors<'a> {
+    pub fn new(control: &'a dyn Control) -> Self {
+        Ancestors { next: control.parent() }
+    }
+}
+impl<'a> Iterator for Ancestors<'a> {
+    type Item = &'a dyn Member;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let member = self.next.take()?;
+        self.next = member.is_control().and_then(|control| control.parent());
+        Some(member)
+    }
+}
+
+/// Mutable counterpart of `Ancestors`, lending out one ancestor at a time.
+pub struct AncestorsMut<'a> {
+    next: Option<*mut dyn Member>,
+    _marker: ::std::marker::PhantomData<&'a mut dyn Member>,
+}
+impl<'a> AncestorsMut<'a> {
+    pub fn new(control: &'a mut dyn Control) -> Self {
+        AncestorsMut { next: control.parent_mut().map(|parent| parent as *mut dyn Member), _marker: ::std::marker::PhantomData }
+    }
+    /// The next member, borrowed from the walk until it is dropped.
+    #[allow(clippy::should_implement_trait)]
+    pub fn next(&mut self) -> Option<&mut dyn Member> {
+        let member = self.next.take()?;
+        self.next = unsafe { &mut *member }.is_control_mut().and_then(|control| control.parent_mut()).map(|parent| parent as *mut dyn Member);
+        Some(unsafe { &mut *member })
+    }
+    pub fn for_each_mut<F: FnMut(&mut dyn Member)>(mut self, mut f: F) {
+        while let Some(member) = self.next() {
+            f(member);
+        }
+    }
+}
@@ -0,0 +1,128 @@
+use std::fmt;
+use std::str::FromStr;
+
+/// 8 bit per channel RGBA color, not premultiplied.
+///
+/// Parses from `#rgb`, `#rgba`, `#rrggbb` and `#rrggbbaa` hex notations, as well as from the basic CSS color names.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct Color {
+    pub r: u8,
+    pub g: u8,
+    pub b: u8,
+    pub a: u8,
+}
+
+impl Color {
+    pub const TRANSPARENT: Color = Color::rgba(0, 0, 0, 0);
+    pub const BLACK: Color = Color::rgb(0, 0, 0);
+    pub const WHITE: Color = Color::rgb(255, 255, 255);
+
+    pub const fn rgb(r: u8, g: u8, b: u8) -> Self {
+        Color { r, g, b, a: 255 }
+    }
+    pub const fn rgba(r: u8, g: u8, b: u8, a: u8) -> Self {
+        Color { r, g, b, a }
+    }
+    pub fn from_name(name: &str) -> Option<Self> {
+        let name = name.to_ascii_lowercase();
+        NAMED.iter().find(|(n, _)| *n == name).map(|(_, c)| *c)
+    }
+    pub fn from_hex(hex: &str) -> Option<Self> {
+        let digits = hex.strip_prefix('#').unwrap_or(hex);
+        if !digits.chars().all(|c| c.is_ascii_hexdigit()) {
+            return None;
+        }
+        let channel = |i: usize, len: usize| u8::from_str_radix(&digits[i * len..(i + 1) * len], 16).ok().map(|v| if len == 1 { v * 17 } else { v });
+        match digits.len() {
+            3 => Some(Color::rgb(channel(0, 1)?, channel(1, 1)?, channel(2, 1)?)),
+            4 => Some(Color::rgba(channel(0, 1)?, channel(1, 1)?, channel(2, 1)?, channel(3, 1)?)),
+            6 => Some(Color::rgb(channel(0, 2)?, channel(1, 2)?, channel(2, 2)?)),
+            8 => Some(Color::rgba(channel(0, 2)?, channel(1, 2)?, channel(2, 2)?, channel(3, 2)?)),
+            _ => None,
+        }
+    }
+}
+
+const NAMED: &[(&str, Color)] = &[
+    ("transparent", Color::TRANSPARENT),
+    ("black", Color::BLACK),
+    ("silver", Color::rgb(192, 192, 192)),
+    ("gray", Color::rgb(128, 128, 128)),
+    ("grey", Color::rgb(128, 128, 128)),
+    ("white", Color::WHITE),
+    ("maroon", Color::rgb(128, 0, 0)),
+    ("red", Color::rgb(255, 0, 0)),
+    ("purple", Color::rgb(128, 0, 128)),
+    ("fuchsia", Color::rgb(255, 0, 255)),
+    ("magenta", Color::rgb(255, 0, 255)),
+    ("green", Color::rgb(0, 128, 0)),
+    ("lime", Color::rgb(0, 255, 0)),
+    ("olive", Color::rgb(128, 128, 0)),
+    ("yellow", Color::rgb(255, 255, 0)),
+    ("navy", Color::rgb(0, 0, 128)),
+    ("blue", Color::rgb(0, 0, 255)),
+    ("teal", Color::rgb(0, 128, 128)),
+    ("aqua", Color::rgb(0, 255, 255)),
+    ("cyan", Color::rgb(0, 255, 255)),
+    ("orange", Color::rgb(255, 165, 0)),
+];
+
+impl FromStr for Color {
+    type Err = crate::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let s = s.trim();
+        let color = if s.starts_with('#') { Color::from_hex(s) } else { Color::from_name(s) };
+        color.ok_or_else(|| crate::Error::Parse(format!("Invalid color: '{}'", s)))
+    }
+}
+impl fmt::Display for Color {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.a == 255 {
+            write!(f, "#{:02x}{:02x}{:02x}", self.r, self.g, self.b)
+        } else {
+            write!(f, "#{:02x}{:02x}{:02x}{:02x}", self.r, self.g, self.b, self.a)
+        }
+    }
+}
+
+impl From<(u8, u8, u8)> for Color {
+    fn from(a: (u8, u8, u8)) -> Color {
+        Color::rgb(a.0, a.1, a.2)
+    }
+}
+impl From<(u8, u8, u8, u8)> for Color {
+    fn from(a: (u8, u8, u8, u8)) -> Color {
+        Color::rgba(a.0, a.1, a.2, a.3)
+    }
+}
+impl From<Color> for (u8, u8, u8, u8) {
+    fn from(c: Color) -> (u8, u8, u8, u8) {
+        (c.r, c.g, c.b, c.a)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn from_str_reads_hex_notations_and_names() {
+        assert_eq!("#f80".parse::<Color>().unwrap(), Color::rgb(255, 136, 0));
+        assert_eq!("#f808".parse::<Color>().unwrap(), Color::rgba(255, 136, 0, 136));
+        assert_eq!(" #1A2b3C ".parse::<Color>().unwrap(), Color::rgb(0x1a, 0x2b, 0x3c));
+        assert_eq!("#1a2b3c4d".parse::<Color>().unwrap(), Color::rgba(0x1a, 0x2b, 0x3c, 0x4d));
+        assert_eq!("Orange".parse::<Color>().unwrap(), Color::rgb(255, 165, 0));
+        assert_eq!("transparent".parse::<Color>().unwrap(), Color::TRANSPARENT);
+        for invalid in ["", "#", "#12", "#12345", "#1234567", "#ggg", "#éé", "1a2b3c", "reddish"].iter() {
+            assert!(matches!(invalid.parse::<Color>(), Err(crate::Error::Parse(_))), "{:?}", invalid);
+        }
+    }
+    #[test]
+    fn display_round_trips() {
+        for color in [Color::rgb(1, 22, 255), Color::rgba(0, 128, 64, 10)].iter() {
+            assert_eq!(color.to_string().parse::<Color>().unwrap(), *color);
+        }
+        assert_eq!(Color::WHITE.to_string(), "#ffffff");
+    }
+}
@@ -0,0 +1,70 @@
+use std::str::FromStr;
+
+/// Font weight, from the thinnest to the boldest.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub enum FontWeight {
+    Thin,
+    Light,
+    #[default]
+    Normal,
+    Medium,
+    Bold,
+    Black,
+}
+/// Accepts the lowercase variant names and the CSS numeric weights (`100` to `900`).
+impl FromStr for FontWeight {
+    type Err = crate::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.trim().to_ascii_lowercase().as_str() {
+            "thin" | "100" | "200" => Ok(FontWeight::Thin),
+            "light" | "300" => Ok(FontWeight::Light),
+            "normal" | "400" => Ok(FontWeight::Normal),
+            "medium" | "500" | "600" => Ok(FontWeight::Medium),
+            "bold" | "700" => Ok(FontWeight::Bold),
+            "black" | "800" | "900" => Ok(FontWeight::Black),
+            other => Err(crate::Error::Parse(format!("Invalid font weight: '{}'", other))),
+        }
+    }
+}
+
+/// An empty family and a zero size stand for the defaults of the backend.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash)]
+pub struct Font {
+    pub family: String,
+    pub size: u16,
+    pub weight: FontWeight,
+    pub italic: bool,
+}
+
+impl Font {
+    pub fn new<S: Into<String>>(family: S, size: u16) -> Self {
+        Font { family: family.into(), size, ..Default::default() }
+    }
+    pub fn with_weight(mut self, weight: FontWeight) -> Self {
+        self.weight = weight;
+        self
+    }
+    pub fn with_italic(mut self, italic: bool) -> Self {
+        self.italic = italic;
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn weight_from_str_reads_names_and_css_numbers() {
+        assert_eq!("bold".parse::<FontWeight>().unwrap(), FontWeight::Bold);
+        assert_eq!(" Light ".parse::<FontWeight>().unwrap(), FontWeight::Light);
+        assert_eq!("400".parse::<FontWeight>().unwrap(), FontWeight::Normal);
+        assert_eq!("600".parse::<FontWeight>().unwrap(), FontWeight::Medium);
+        assert_eq!("900".parse::<FontWeight>().unwrap(), FontWeight::Black);
+        for invalid in ["", "heavy", "450", "1000", "-100"].iter() {
+            assert!(matches!(invalid.parse::<FontWeight>(), Err(crate::Error::Parse(_))), "{:?}", invalid);
+        }
+        assert!(FontWeight::Thin < FontWeight::Black);
+    }
+}
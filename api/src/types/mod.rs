@@ -1,7 +1,16 @@
 use crate::controls;
 
+use std::fmt;
+
 pub mod imp;
 
+mod color;
+mod font;
+mod selector;
+pub use self::color::Color;
+pub use self::font::{Font, FontWeight};
+pub use self::selector::Selector;
+
 pub mod adapter {
 	pub use crate::inner::adapter::{Node, Change, FnNodeItem};
 }
@@ -12,6 +21,9 @@ pub mod matrix {
 pub use crate::inner::{
     auto::{AsAny, Spawnable},
     adapter::Adapter,
+    clipboard::Clipboard,
+    timer::{Clock, SystemClock},
+    executor::{Background, LocalFuture},
 };
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -66,6 +78,62 @@ pub enum MessageSeverity {
     Info,
     Warning,
     Alert,
+    Question,
+}
+
+/// Extra input requested from the user alongside the message actions.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum MessageInput {
+    None,
+    /// Single line text entry with the default value.
+    Text(String),
+    /// Selection among the listed options, with the index of the default one.
+    Choice(Vec<String>, usize),
+    /// Checkbox (e.g. "Don't ask again") with its label and default state.
+    Confirm(String, bool),
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum MessageInputValue {
+    None,
+    Text(String),
+    Choice(usize),
+    Confirm(bool),
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MessageResponse {
+    /// Index of the pressed action, in the order the actions were given.
+    pub action: usize,
+    pub title: String,
+    pub input: MessageInputValue,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FileDialogMode {
+    Open,
+    Save,
+    SelectFolder,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FileFilter {
+    pub name: String,
+    pub patterns: Vec<String>,
+}
+impl FileFilter {
+    pub fn with_patterns<S: Into<String>, P: AsRef<str>>(name: S, patterns: &[P]) -> Self {
+        FileFilter {
+            name: name.into(),
+            patterns: patterns.iter().map(|p| p.as_ref().into()).collect(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ClipboardFormat {
+    Text,
+    Image,
 }
 
 #[derive(Debug, Clone, PartialEq)]
@@ -80,10 +148,51 @@ pub enum ImageScalePolicy {
     FitCenter,  // TODO variants
                 // TODO Tile
 }
-#[derive(Debug, Clone, Copy, PartialEq)]
+/// How to look up members, see `Application::find_member` and `Container::find_control`.
+/// Backends should test members with `FindBy::matches` instead of matching on the variants, more may come.
+#[derive(Clone, Copy)]
+#[non_exhaustive]
 pub enum FindBy<'a> {
     Id(crate::ids::Id),
     Tag(&'a str),
+    /// Type name as in `utils::member_type_name`, e.g. `"Button"`.
+    Type(&'a str),
+    Predicate(&'a dyn Fn(&dyn controls::Member) -> bool),
+    Selector(&'a Selector),
+}
+impl<'a> FindBy<'a> {
+    pub fn matches(&self, member: &dyn controls::Member) -> bool {
+        match *self {
+            FindBy::Id(id) => member.id() == id,
+            FindBy::Tag(tag) => member.tag().is_some_and(|mytag| tag == mytag),
+            FindBy::Type(type_name) => crate::utils::member_type_name(member) == type_name,
+            FindBy::Predicate(predicate) => predicate(member),
+            FindBy::Selector(selector) => selector.matches(member),
+        }
+    }
+}
+impl<'a> fmt::Debug for FindBy<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match *self {
+            FindBy::Id(id) => f.debug_tuple("Id").field(&id).finish(),
+            FindBy::Tag(tag) => f.debug_tuple("Tag").field(&tag).finish(),
+            FindBy::Type(type_name) => f.debug_tuple("Type").field(&type_name).finish(),
+            FindBy::Predicate(_) => f.write_str("Predicate(..)"),
+            FindBy::Selector(selector) => f.debug_tuple("Selector").field(&selector.to_string()).finish(),
+        }
+    }
+}
+impl<'a> PartialEq for FindBy<'a> {
+    fn eq(&self, other: &Self) -> bool {
+        match (*self, *other) {
+            (FindBy::Id(a), FindBy::Id(b)) => a == b,
+            (FindBy::Tag(a), FindBy::Tag(b)) => a == b,
+            (FindBy::Type(a), FindBy::Type(b)) => a == b,
+            (FindBy::Predicate(a), FindBy::Predicate(b)) => a as *const _ as *const () == b as *const _ as *const (),
+            (FindBy::Selector(a), FindBy::Selector(b)) => a == b,
+            _ => false,
+        }
+    }
 }
 
 pub enum VecItemChangeOption<T: Sized> {
@@ -113,6 +222,20 @@ impl<T: Sized> VecItemChangeOption<T> {
 	pub fn is_none(&self) -> bool {
 		!self.is_some()
 	}
+	pub fn try_as_ref(&self) -> Result<&T, crate::Error> {
+		match self {
+			Self::Insert(ref value) => Ok(value),
+			Self::Replace(ref value) => Ok(value),
+			Self::Remove => Err(crate::Error::Empty),
+		}
+	}
+	pub fn try_as_mut(&mut self) -> Result<&mut T, crate::Error> {
+		match self {
+			Self::Insert(ref mut value) => Ok(value),
+			Self::Replace(ref mut value) => Ok(value),
+			Self::Remove => Err(crate::Error::Empty),
+		}
+	}
 }
 impl<T: Sized> AsRef<T> for VecItemChangeOption<T> {
 	fn as_ref(&self) -> &T {
@@ -388,18 +511,125 @@ impl<'a, K: Sized> RecursiveTupleVecIterator<'a, K> {
     }
 }
 
+/// When the `Application` exits by itself, without an explicit `prepare_exit` call.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum QuitPolicy {
+    /// Once no windows nor trays remain.
+    #[default]
+    OnLastRootClosed,
+    /// Once the last window gets closed, even if trays remain.
+    OnLastWindowClosed,
+    /// Only on `prepare_exit` or `exit`.
+    Explicit,
+}
+
+/// Color scheme of the system, see `Application::color_scheme`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum ColorScheme {
+    #[default]
+    Light,
+    Dark,
+    HighContrast,
+}
+
 pub enum ApplicationResult {
     New(Box<dyn controls::Application>),
     Existing(Box<dyn controls::Application>),
     ErrorNonUiThread,
+    ErrorAlreadyInitialized,
+    /// Another instance of a single instance Application runs and received the arguments.
+    Forwarded,
     ErrorUnspecified,
 }
 impl ApplicationResult {
-    pub fn unwrap(self) -> Box<dyn controls::Application> {
+    pub fn into_result(self) -> Result<Box<dyn controls::Application>, crate::Error> {
         match self {
-            ApplicationResult::New(app) | ApplicationResult::Existing(app) => app,
-            ApplicationResult::ErrorNonUiThread => panic!("Application requested from non-UI thread"),
-            ApplicationResult::ErrorUnspecified => panic!("Error getting Application"),
+            ApplicationResult::New(app) | ApplicationResult::Existing(app) => Ok(app),
+            ApplicationResult::ErrorNonUiThread => Err(crate::Error::NonUiThread),
+            ApplicationResult::ErrorAlreadyInitialized => Err(crate::Error::AlreadyInitialized),
+            ApplicationResult::Forwarded => Err(crate::Error::Forwarded),
+            ApplicationResult::ErrorUnspecified => Err(crate::Error::Unspecified),
         }
     }
 }
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct Modifiers {
+    pub shift: bool,
+    pub ctrl: bool,
+    pub alt: bool,
+    pub meta: bool,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum KeyAction {
+    Press,
+    Release,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct KeyEvent {
+    pub action: KeyAction,
+    /// Backend-independent key name, e.g. `"a"`, `"Enter"` or `"ArrowLeft"`.
+    pub key: String,
+    pub modifiers: Modifiers,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PointerButton {
+    Left,
+    Right,
+    Middle,
+    Other(u8),
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PointerAction {
+    Press(PointerButton),
+    Release(PointerButton),
+    Move,
+    Scroll(i32, i32),
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PointerEvent {
+    pub action: PointerAction,
+    /// Position relative to the control the event was originally delivered to.
+    pub position: (i32, i32),
+    pub modifiers: Modifiers,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Event {
+    Click,
+    Key(KeyEvent),
+    Pointer(PointerEvent),
+}
+
+/// An event travelling from its target member up through the parents to the root.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BubblingEvent {
+    event: Event,
+    target: crate::ids::Id,
+    stopped: bool,
+}
+impl BubblingEvent {
+    pub fn new(event: Event, target: crate::ids::Id) -> Self {
+        BubblingEvent { event, target, stopped: false }
+    }
+    pub fn event(&self) -> &Event {
+        &self.event
+    }
+    /// Id of the member the event originated from.
+    pub fn target(&self) -> crate::ids::Id {
+        self.target
+    }
+    /// Prevents the event from reaching the parents of the current member.
+    /// Remaining handlers of the current member are still invoked.
+    pub fn stop_propagation(&mut self) {
+        self.stopped = true;
+    }
+    pub fn is_propagation_stopped(&self) -> bool {
+        self.stopped
+    }
+}
@@ -0,0 +1,190 @@
+use crate::controls::Member;
+use crate::traversal::Ancestors;
+
+use std::fmt;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Combinator {
+    /// Whitespace, any ancestor.
+    Descendant,
+    /// `>`, the direct parent.
+    Child,
+}
+
+/// One compound of a selector: `Type#tag.class.other`, any part being optional (`*` matches everything).
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+struct Compound {
+    type_name: Option<String>,
+    tag: Option<String>,
+    classes: Vec<String>,
+}
+
+impl Compound {
+    fn matches(&self, member: &dyn Member) -> bool {
+        if let Some(ref type_name) = self.type_name {
+            if crate::utils::member_type_name(member) != type_name {
+                return false;
+            }
+        }
+        if let Some(ref tag) = self.tag {
+            if member.tag().as_ref().map(|t| t.as_ref()) != Some(tag.as_str()) {
+                return false;
+            }
+        }
+        self.classes.iter().all(|class| member.has_class(class))
+    }
+}
+
+/// CSS-like member selector, e.g. `"Window > LinearLayout#form Button.primary"`.
+///
+/// Types are the ones of `utils::member_type_name`, `#` selects by tag and `.` by class.
+/// Compounds are combined by whitespace (any ancestor) or `>` (the direct parent).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Selector {
+    source: String,
+    /// The combinator relates each compound to the previous one, it is ignored for the first.
+    compounds: Vec<(Combinator, Compound)>,
+}
+
+impl Selector {
+    pub fn parse(source: &str) -> Result<Selector, crate::Error> {
+        let error = |reason: &str| crate::Error::InvalidSelector(format!("{} in '{}'", reason, source));
+        let mut compounds = Vec::new();
+        let mut combinator = Combinator::Descendant;
+        let mut chars = source.chars().peekable();
+        loop {
+            while chars.peek().is_some_and(|c| c.is_whitespace()) {
+                chars.next();
+            }
+            match chars.peek() {
+                None => break,
+                Some('>') => {
+                    if compounds.is_empty() || combinator == Combinator::Child {
+                        return Err(error("misplaced '>'"));
+                    }
+                    chars.next();
+                    combinator = Combinator::Child;
+                    continue;
+                }
+                _ => {}
+            }
+            let mut compound = Compound::default();
+            if chars.peek() == Some(&'*') {
+                chars.next();
+            } else {
+                let name = take_name(&mut chars);
+                if !name.is_empty() {
+                    compound.type_name = Some(name);
+                }
+            }
+            while let Some(&c) = chars.peek() {
+                if c != '#' && c != '.' {
+                    break;
+                }
+                chars.next();
+                let name = take_name(&mut chars);
+                if name.is_empty() {
+                    return Err(error("empty tag or class"));
+                }
+                if c == '#' {
+                    if compound.tag.is_some() {
+                        return Err(error("more than one tag"));
+                    }
+                    compound.tag = Some(name);
+                } else {
+                    compound.classes.push(name);
+                }
+            }
+            match chars.peek() {
+                Some(c) if !c.is_whitespace() && *c != '>' => return Err(error(&format!("unexpected '{}'", c))),
+                _ => {}
+            }
+            compounds.push((combinator, compound));
+            combinator = Combinator::Descendant;
+        }
+        if compounds.is_empty() {
+            return Err(error("empty selector"));
+        }
+        if combinator == Combinator::Child {
+            return Err(error("trailing '>'"));
+        }
+        Ok(Selector { source: source.into(), compounds })
+    }
+    /// Counts of the tags, classes and types over all compounds. Rules with a greater one take precedence.
+    pub fn specificity(&self) -> (usize, usize, usize) {
+        self.compounds.iter().fold((0, 0, 0), |(tags, classes, types), (_, compound)| {
+            (tags + compound.tag.is_some() as usize, classes + compound.classes.len(), types + compound.type_name.is_some() as usize)
+        })
+    }
+    pub fn matches(&self, member: &dyn Member) -> bool {
+        let ancestors: Vec<&dyn Member> = match member.is_control() {
+            Some(control) => Ancestors::new(control).collect(),
+            None => Vec::new(),
+        };
+        self.matches_at(self.compounds.len() - 1, member, &ancestors)
+    }
+
+    fn matches_at(&self, index: usize, member: &dyn Member, ancestors: &[&dyn Member]) -> bool {
+        let (combinator, ref compound) = self.compounds[index];
+        if !compound.matches(member) {
+            return false;
+        }
+        if index == 0 {
+            return true;
+        }
+        match combinator {
+            Combinator::Child => !ancestors.is_empty() && self.matches_at(index - 1, ancestors[0], &ancestors[1..]),
+            Combinator::Descendant => (0..ancestors.len()).any(|i| self.matches_at(index - 1, ancestors[i], &ancestors[i + 1..])),
+        }
+    }
+}
+
+impl fmt::Display for Selector {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.source)
+    }
+}
+
+fn take_name<I: Iterator<Item = char>>(chars: &mut ::std::iter::Peekable<I>) -> String {
+    let mut name = String::new();
+    while let Some(&c) = chars.peek() {
+        if c.is_alphanumeric() || c == '_' || c == '-' {
+            name.push(c);
+            chars.next();
+        } else {
+            break;
+        }
+    }
+    name
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_reads_compounds_and_combinators() {
+        let selector = Selector::parse("  Window > LinearLayout#form  Button.primary.wide ").unwrap();
+        assert_eq!(selector.compounds.len(), 3);
+        assert_eq!(selector.compounds[1].0, Combinator::Child);
+        assert_eq!(selector.compounds[1].1.tag.as_deref(), Some("form"));
+        assert_eq!(selector.compounds[2].0, Combinator::Descendant);
+        assert_eq!(selector.compounds[2].1.classes, vec!["primary", "wide"]);
+        assert_eq!(selector.to_string(), "  Window > LinearLayout#form  Button.primary.wide ");
+        assert_eq!(Selector::parse("*").unwrap().compounds[0].1, Compound::default());
+        assert_eq!(Selector::parse("Frame>Button").unwrap().compounds[1].0, Combinator::Child);
+    }
+    #[test]
+    fn parse_rejects_malformed_selectors() {
+        for source in ["", "   ", "> Button", "Frame >", "Frame > > Button", "Button#", "Button.", "#a#b", "Button:hover"].iter() {
+            assert!(matches!(Selector::parse(source), Err(crate::Error::InvalidSelector(_))), "{:?}", source);
+        }
+    }
+    #[test]
+    fn specificity_counts_tags_classes_and_types() {
+        assert_eq!(Selector::parse("*").unwrap().specificity(), (0, 0, 0));
+        assert_eq!(Selector::parse("Window Button").unwrap().specificity(), (0, 0, 2));
+        assert_eq!(Selector::parse("#form > Button.primary.wide").unwrap().specificity(), (1, 2, 1));
+        assert!(Selector::parse("#a").unwrap().specificity() > Selector::parse("Frame .a.b.c").unwrap().specificity());
+    }
+}
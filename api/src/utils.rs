@@ -30,6 +30,89 @@ where
     base_to_impl_mut::<T>(this)
 }
 
+/// The application of the current UI thread, e.g. for futures spawned with `Application::spawn_local`.
+pub fn application_mut() -> Option<&'static mut dyn controls::Application> {
+    crate::runtime::get_dyn()
+}
+
+/// Checks that the child belongs to the same backend as the container it is about to be added to.
+pub fn check_backend(parent: &dyn controls::Member, child: &dyn controls::Member) -> Result<(), crate::Error> {
+    #[cfg(feature = "type_check")]
+    unsafe {
+        if controls::Member::type_id(parent) != controls::Member::type_id(child) {
+            return Err(crate::Error::IncompatibleBackend);
+        }
+    }
+    let _ = (parent, child);
+    Ok(())
+}
+
+/// Human readable kind of the member, e.g. `"Button"` or `"Window"`.
+pub fn member_type_name(member: &dyn controls::Member) -> &'static str {
+    if let Some(control) = member.is_control() {
+        if control.is_button().is_some() {
+            "Button"
+        } else if control.is_text().is_some() {
+            "Text"
+        } else if control.is_image().is_some() {
+            "Image"
+        } else if control.is_progress_bar().is_some() {
+            "ProgressBar"
+        } else if control.is_linear_layout().is_some() {
+            "LinearLayout"
+        } else if control.is_splitted().is_some() {
+            "Splitted"
+        } else if control.is_frame().is_some() {
+            "Frame"
+        } else if control.is_list().is_some() {
+            "List"
+        } else if control.is_tree().is_some() {
+            "Tree"
+        } else if control.is_table().is_some() {
+            "Table"
+        } else {
+            "Control"
+        }
+    } else if member.is_window().is_some() {
+        "Window"
+    } else if member.is_tray().is_some() {
+        "Tray"
+    } else if member.is_message().is_some() {
+        "Message"
+    } else if member.is_file_dialog().is_some() {
+        "FileDialog"
+    } else {
+        "Member"
+    }
+}
+
+/// Snapshots the member and all its children, with their layout attributes, measured sizes, coords and visibility.
+pub fn dump_layout(member: &dyn controls::Member) -> layout::LayoutDump {
+    let children = crate::traversal::children(member).into_iter().map(dump_layout).collect();
+    let laid_out = member.is_has_layout();
+    layout::LayoutDump {
+        type_name: member_type_name(member),
+        id: member.id(),
+        tag: member.tag().map(|tag| tag.into_owned()),
+        classes: member.classes().to_vec(),
+        attributes: laid_out.map(|l| layout::Attributes { width: l.layout_width(), height: l.layout_height() }),
+        margin: laid_out.map(|l| l.layout_margin()),
+        measured: member.is_has_size().map(|s| s.size()),
+        coords: member.is_control().and_then(|c| c.coords()),
+        visibility: member.is_has_visibility().map(|v| v.visibility()),
+        children,
+    }
+}
+
+/// Scale factor of the window the member is in, `1.0` outside of any window.
+pub fn scale_factor(member: &dyn controls::Member) -> f32 {
+    let root = match member.is_control() {
+        Some(control) => crate::traversal::Ancestors::new(control).last(),
+        None => Some(member),
+    };
+    root.and_then(|root| root.is_window()).map_or(1.0, |window| window.scale_factor())
+}
+
 #[inline]
 pub fn coord_to_size(a: i32) -> u16 {
     ::std::cmp::max(0, a) as u16
@@ -50,21 +133,28 @@ where
     &mut *(this as *mut _ as *mut T)
 }
 */
-pub fn find_by_mut<'a>(control: &'a mut dyn controls::Control, arg: types::FindBy<'a>) -> Option<&'a mut dyn controls::Control> {
-    match arg {
-        types::FindBy::Id(id) => {
-            if control.as_member_mut().id() == id {
-                return Some(control);
-            }
-        },
-        types::FindBy::Tag(tag) => {
-            if let Some(mytag) = control.as_member_mut().tag() {
-                if tag == mytag {
-                    return Some(control);
-                }
-            }
+/// Delivers the event to `target` and then to each of its parents up to the root,
+/// until one of the handlers stops the propagation. Returns `true` if it was stopped.
+pub fn dispatch_event(target: &mut dyn controls::Member, event: types::Event) -> bool {
+    let mut event = types::BubblingEvent::new(event, target.id());
+    let mut current = target as *mut dyn controls::Member;
+    loop {
+        let member = unsafe { &mut *current };
+        member.as_base_mut().call_on_event(&mut event);
+        if event.is_propagation_stopped() {
+            return true;
+        }
+        match member.is_control_mut().and_then(|control| control.parent_mut()) {
+            Some(parent) => current = parent as *mut dyn controls::Member,
+            None => return false,
         }
     }
+}
+
+pub fn find_by_mut<'a>(control: &'a mut dyn controls::Control, arg: types::FindBy<'a>) -> Option<&'a mut dyn controls::Control> {
+    if arg.matches(control.as_member()) {
+        return Some(control);
+    }
     if let Some(c) = control.is_container_mut() {
         let ret = c.find_control_mut(arg.clone());
         if ret.is_some() {
@@ -74,19 +164,8 @@ pub fn find_by_mut<'a>(control: &'a mut dyn controls::Control, arg: types::FindB
     None
 }
 pub fn find_by<'a>(control: &'a dyn controls::Control, arg: types::FindBy<'a>) -> Option<&'a dyn controls::Control> {
-    match arg {
-        types::FindBy::Id(id) => {
-            if control.as_member().id() == id {
-                return Some(control);
-            }
-        },
-        types::FindBy::Tag(tag) => {
-            if let Some(mytag) = control.as_member().tag() {
-                if tag == mytag {
-                    return Some(control);
-                }
-            }
-        }
+    if arg.matches(control.as_member()) {
+        return Some(control);
     }
     if let Some(c) = control.is_container() {
         let ret = c.find_control(arg.clone());
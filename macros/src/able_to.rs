@@ -13,7 +13,6 @@ use crate::maybe::Maybe;
 pub fn make(item: proc_macro::TokenStream) -> proc_macro::TokenStream {
     let parsed = parse_macro_input!(item as AbleTo);
     let t = quote!(#parsed);
-    dbg!(format!("{:#}", t));
     proc_macro::TokenStream::from(t)
 }
 
@@ -114,6 +113,8 @@ impl ToTokens for AbleTo {
 
         let ident_fn = Ident::new(&format!("{}", ident).to_snake_case(), Span::call_site());
         let on_ident_fn = Ident::new(&format!("on_{}", ident).to_snake_case(), Span::call_site());
+        let add_on_ident_fn = Ident::new(&format!("add_on_{}", ident).to_snake_case(), Span::call_site());
+        let remove_on_ident_fn = Ident::new(&format!("remove_on_{}", ident).to_snake_case(), Span::call_site());
 
         let as_into = &crate::as_into::AsInto { ident_camel: &ident_able };
 
@@ -191,6 +192,8 @@ impl ToTokens for AbleTo {
             pub trait #ident_able: #static_ + AsAny #(+#extends)*{
                 fn #ident_fn(&mut self, #(#param_names: #params,)* skip_callbacks: bool) #ret;
                 fn #on_ident_fn(&mut self, callback: Option<#on_ident>);
+                fn #add_on_ident_fn(&mut self, callback: #on_ident) -> CallbackId;
+                fn #remove_on_ident_fn(&mut self, id: CallbackId) -> bool;
 
                 #custom
                 #as_into
@@ -198,6 +201,8 @@ impl ToTokens for AbleTo {
             pub trait #ident_able_inner: #(#extends_inner+)* #static_inner {
                 fn #ident_fn(&mut self, #(#param_names: #params,)* skip_callbacks: bool) #ret;
                 fn #on_ident_fn(&mut self, callback: Option<#on_ident>);
+                fn #add_on_ident_fn(&mut self, callback: #on_ident) -> CallbackId;
+                fn #remove_on_ident_fn(&mut self, id: CallbackId) -> bool;
 
                 #custom
             }
@@ -12,7 +12,6 @@ pub fn make(item: proc_macro::TokenStream, is_abstract: bool) -> proc_macro::Tok
     let mut parsed = parse_macro_input!(item as Define);
     parsed._abstract = is_abstract;
     let t = quote!(#parsed);
-    dbg!(format!("{:#}", t));
     proc_macro::TokenStream::from(t)
 }
 
@@ -15,7 +15,6 @@ pub fn make(item: proc_macro::TokenStream, use_reactor: bool, use_get_set: bool)
     parsed.use_reactor = use_reactor;
     parsed.use_get_set = use_get_set;
     let t = quote!(#parsed);
-    dbg!(format!("{:#}", t));
     proc_macro::TokenStream::from(t)
 }
 
@@ -209,8 +208,13 @@ impl ToTokens for Has {
             let on_ident_fn = Ident::new(&format!("on_{}", ident).to_snake_case(), Span::call_site());
             let on_ident = Ident::new(&format!("On{}", ident).to_upper_camel_case(), Span::call_site());
 
+            let add_on_ident_fn = Ident::new(&format!("add_on_{}", ident).to_snake_case(), Span::call_site());
+            let remove_on_ident_fn = Ident::new(&format!("remove_on_{}", ident).to_snake_case(), Span::call_site());
+
             quote! {
                 fn #on_ident_fn(&mut self, callback: Option<#on_ident>);
+                fn #add_on_ident_fn(&mut self, callback: #on_ident) -> CallbackId;
+                fn #remove_on_ident_fn(&mut self, id: CallbackId) -> bool;
             }
         } else { quote! {} };
         
@@ -218,8 +222,13 @@ impl ToTokens for Has {
             let on_ident_fn = Ident::new(&format!("on_{}", ident).to_snake_case(), Span::call_site());
             let on_ident = Ident::new(&format!("On{}", ident).to_upper_camel_case(), Span::call_site());
 
+            let add_on_ident_fn = Ident::new(&format!("add_on_{}", ident).to_snake_case(), Span::call_site());
+            let remove_on_ident_fn = Ident::new(&format!("remove_on_{}", ident).to_snake_case(), Span::call_site());
+
             quote! {
                 fn #on_ident_fn(&mut self #(,#extends_base_names: &mut #extends_base)* ,callback: Option<#on_ident>);
+                fn #add_on_ident_fn(&mut self #(,#extends_base_names: &mut #extends_base)* ,callback: #on_ident) -> CallbackId;
+                fn #remove_on_ident_fn(&mut self #(,#extends_base_names: &mut #extends_base)* ,id: CallbackId) -> bool;
             }
         } else { quote! {} };
         
@@ -7,7 +7,6 @@ use syn::{parse_macro_input, Ident};
 pub fn make(item: proc_macro::TokenStream) -> proc_macro::TokenStream {
     let parsed = parse_macro_input!(item as Maybe);
     let t = quote!(#parsed);
-    dbg!(format!("{:#}", t));
     proc_macro::TokenStream::from(t)
 }
 
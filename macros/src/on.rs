@@ -8,7 +8,6 @@ use syn::{parenthesized, parse_macro_input, token, Ident, Token, Type};
 pub fn make(item: proc_macro::TokenStream) -> proc_macro::TokenStream {
     let parsed = parse_macro_input!(item as On);
     let t = quote!(#parsed);
-    dbg!(format!("{:#}", t));
     proc_macro::TokenStream::from(t)
 }
 
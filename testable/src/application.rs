@@ -1,10 +1,33 @@
 use crate::common::{self, *};
-use std::{thread, time, any::TypeId};
+use std::{thread, time, any::TypeId, cell::Cell, rc::Rc};
+
+/// Clock of the testable timers, which only moves when advanced by the test.
+#[derive(Clone)]
+pub struct TestableClock {
+    start: time::Instant,
+    offset: Rc<Cell<time::Duration>>,
+}
+impl TestableClock {
+    fn new() -> Self {
+        TestableClock { start: time::Instant::now(), offset: Rc::new(Cell::new(time::Duration::from_secs(0))) }
+    }
+    fn advance(&self, by: time::Duration) {
+        self.offset.set(self.offset.get() + by);
+    }
+}
+impl types::Clock for TestableClock {
+    fn now(&self) -> time::Instant {
+        self.start + self.offset.get()
+    }
+}
 
 pub struct TestableApplication {
     pub(crate) root: *mut Application,
     name: String,
     sleep: u32,
+    clipboard: crate::clipboard::TestableClipboard,
+    clock: TestableClock,
+    exiting: bool,
 }
 
 pub type Application = AApplication<TestableApplication>;
@@ -15,6 +38,9 @@ impl<O: controls::Application> NewApplicationInner<O> for TestableApplication {
         	root: u as *mut _ as *mut Application,
             name: name.into(),
             sleep: 0,
+            clipboard: Default::default(),
+            clock: TestableClock::new(),
+            exiting: false,
         }
     }
 }
@@ -26,14 +52,110 @@ impl TestableApplication {
     pub fn get_mut(&mut self) -> &mut Application {
         unsafe { &mut *self.root }
     }
+    /// Moves the testable clock forward and fires the timers that became due.
+    pub fn advance_time(&mut self, by: time::Duration) {
+        self.clock.advance(by);
+        self.get_mut().run_timers();
+    }
+    /// Simulates a key press or release delivered to the member with the given id, bubbling it up to its root.
+    /// Returns `true` if a handler stopped the propagation.
+    pub fn send_key(&mut self, target: ids::Id, event: types::KeyEvent) -> bool {
+        self.get_mut().dispatch_event(target, types::Event::Key(event))
+    }
+    /// Simulates pointer input delivered to the member with the given id, bubbling it up to its root.
+    /// Returns `true` if a handler stopped the propagation.
+    pub fn send_pointer(&mut self, target: ids::Id, event: types::PointerEvent) -> bool {
+        self.get_mut().dispatch_event(target, types::Event::Pointer(event))
+    }
+    /// Simulates a switch of the system theme, restyling everything and firing `on_theme_change`.
+    pub fn set_color_scheme(&mut self, scheme: types::ColorScheme) {
+        self.get_mut().set_color_scheme(scheme);
+    }
+    /// Simulates moving all windows to a display with the given scale factor, e.g. `2.0` for a HiDPI one.
+    pub fn set_scale_factor(&mut self, scale_factor: f32) {
+        for window in self.get_mut().base.windows.iter_mut() {
+            window.as_any_mut().downcast_mut::<crate::window::Window>().unwrap().set_scale_factor::<crate::window::Window>(scale_factor);
+        }
+    }
+    /// Renders the bounds of every control of every window, the windows stacked top to bottom.
+    /// Each nesting level gets its own color, zero sized controls are drawn as a cross.
+    pub fn layout_overlay(&self) -> image::RgbaImage {
+        let dumps: Vec<layout::LayoutDump> = self.get().base.windows.iter().map(|w| w.dump_layout()).collect();
+        let width = dumps.iter().filter_map(|d| d.measured).map(|(w, _)| w as u32).max().unwrap_or(0);
+        let height = dumps.iter().filter_map(|d| d.measured).map(|(_, h)| h as u32).sum();
+        let mut img = image::RgbaImage::from_pixel(cmp::max(width, 1), cmp::max(height, 1), image::Rgba([255, 255, 255, 255]));
+        let mut y = 0;
+        for dump in dumps.iter() {
+            draw_bounds(&mut img, dump, (0, y), 0);
+            y += dump.measured.map(|(_, h)| h as i32).unwrap_or(0);
+        }
+        img
+    }
+    pub fn save_layout_overlay<P: AsRef<std::path::Path>>(&self, path: P) -> image::ImageResult<()> {
+        self.layout_overlay().save(path)
+    }
+    /// The accessibility trees of all windows and trays, as a screen reader would get them.
+    pub fn accessibility_tree(&self) -> Vec<a11y::AccessibleNode> {
+        self.roots().map(a11y::snapshot).collect()
+    }
+    /// The accessibility problems of the shown members of all windows and trays, like an `Image` or an icon-only `Button` without a name.
+    /// Meant for failing CI runs: `assert!(app.lint_accessibility().is_empty())`.
+    pub fn lint_accessibility(&self) -> Vec<a11y::Issue> {
+        self.accessibility_tree().iter().flat_map(a11y::lint).collect()
+    }
+}
+
+const OVERLAY_COLORS: [[u8; 4]; 6] = [
+    [0, 0, 0, 255],
+    [220, 40, 40, 255],
+    [40, 160, 40, 255],
+    [40, 80, 220, 255],
+    [200, 140, 0, 255],
+    [160, 40, 200, 255],
+];
+
+fn draw_bounds(img: &mut image::RgbaImage, dump: &layout::LayoutDump, origin: (i32, i32), depth: usize) {
+    if dump.visibility == Some(types::Visibility::Gone) {
+        return;
+    }
+    let (x, y) = dump.coords.map(|(x, y)| (origin.0 + x, origin.1 + y)).unwrap_or(origin);
+    let (w, h) = dump.measured.map(|(w, h)| (w as i32, h as i32)).unwrap_or((0, 0));
+    let color = image::Rgba(OVERLAY_COLORS[depth % OVERLAY_COLORS.len()]);
+    let mut put = |px: i32, py: i32| {
+        if px >= 0 && py >= 0 && (px as u32) < img.width() && (py as u32) < img.height() {
+            img.put_pixel(px as u32, py as u32, color);
+        }
+    };
+    if w == 0 || h == 0 {
+        for d in -3..4 {
+            put(x + d, y + d);
+            put(x + d, y - d);
+        }
+    } else {
+        for px in x..x + w {
+            put(px, y);
+            put(px, y + h - 1);
+        }
+        for py in y..y + h {
+            put(x, py);
+            put(x + w - 1, py);
+        }
+    }
+    for child in dump.children.iter() {
+        draw_bounds(img, child, (x, y), depth + 1);
+    }
 }
 
 impl ApplicationInner for TestableApplication {
     fn with_name<S: AsRef<str>>(name: S) -> Box<dyn controls::Application> {
         let mut b: Box<mem::MaybeUninit<Application>> = Box::new_uninit();
-        let ab = AApplication::with_inner(
+        let mut ab = AApplication::with_inner(
             <Self as NewApplicationInner<Application>>::with_uninit_params(b.as_mut(), name.as_ref()),
         );
+        let clock = ab.inner().clock.clone();
+        ab.base.timers.set_clock(Box::new(clock));
+        let ui_thread = thread::current();
+        ab.base.executor.set_notifier(Box::new(move || ui_thread.unpark()));
         unsafe {
 	        b.as_mut_ptr().write(ab);
 	        b.assume_init()
@@ -61,42 +183,21 @@ impl ApplicationInner for TestableApplication {
     }
     fn close_root(&mut self, arg: types::FindBy, skip_callbacks: bool) -> bool {
         let base = &mut self.get_mut().base; 
-        match arg {
-            types::FindBy::Id(id) => {
-                (0..base.windows.len()).into_iter().find(|i| if base.windows[*i].id() == id 
-                    && base.windows[*i].as_any_mut().downcast_mut::<crate::window::Window>().unwrap().inner_mut().inner_mut().inner_mut().inner_mut().close(skip_callbacks) {
-                        base.windows.remove(*i);
-                        true
-                    } else {
-                        false
-                }).is_some()
-                || 
-                (0..base.trays.len()).into_iter().find(|i| if base.trays[*i].id() == id 
-                    && base.trays[*i].as_any_mut().downcast_mut::<crate::tray::Tray>().unwrap().inner_mut().close(skip_callbacks) {
-                        base.trays.remove(*i);
-                        true
-                    } else {
-                        false
-                }).is_some()
-            }
-            types::FindBy::Tag(ref tag) => {
-                (0..base.windows.len()).into_iter().find(|i| if base.windows[*i].tag().is_some() && base.windows[*i].tag().unwrap() == Cow::Borrowed(tag.into()) 
-                    && base.windows[*i].as_any_mut().downcast_mut::<crate::window::Window>().unwrap().inner_mut().inner_mut().inner_mut().inner_mut().close(skip_callbacks) {
-                        base.windows.remove(*i);
-                        true
-                    } else {
-                        false
-                }).is_some()
-                || 
-                (0..base.trays.len()).into_iter().find(|i| if base.trays[*i].tag().is_some() && base.trays[*i].tag().unwrap() == Cow::Borrowed(tag.into()) 
-                    && base.trays[*i].as_any_mut().downcast_mut::<crate::tray::Tray>().unwrap().inner_mut().close(skip_callbacks) {
-                        base.trays.remove(*i);
-                        true
-                    } else {
-                        false
-                }).is_some()
-            }
-        }
+        (0..base.windows.len()).into_iter().find(|i| if arg.matches(base.windows[*i].as_member()) 
+            && base.windows[*i].as_any_mut().downcast_mut::<crate::window::Window>().unwrap().inner_mut().inner_mut().inner_mut().inner_mut().close(skip_callbacks) {
+                base.windows.remove(*i);
+                true
+            } else {
+                false
+        }).is_some()
+        || 
+        (0..base.trays.len()).into_iter().find(|i| if arg.matches(base.trays[*i].as_member()) 
+            && base.trays[*i].as_any_mut().downcast_mut::<crate::tray::Tray>().unwrap().inner_mut().close(skip_callbacks) {
+                base.trays.remove(*i);
+                true
+            } else {
+                false
+        }).is_some()
     }
     fn name<'a>(&'a self) -> Cow<'a, str> {
         Cow::Borrowed(self.name.as_str())
@@ -107,6 +208,12 @@ impl ApplicationInner for TestableApplication {
     fn set_frame_sleep(&mut self, value: u32) {
         self.sleep = value;
     }
+    fn clipboard(&self) -> &dyn types::Clipboard {
+        &self.clipboard
+    }
+    fn clipboard_mut(&mut self) -> &mut dyn types::Clipboard {
+        &mut self.clipboard
+    }
     fn start(&mut self) {
         {
         	let base = &mut self.get_mut().base; 
@@ -114,48 +221,38 @@ impl ApplicationInner for TestableApplication {
         		window.as_any_mut().downcast_mut::<crate::window::Window>().unwrap().inner_mut().inner_mut().inner_mut().inner_mut().inner_mut().draw();
         	}
         }
-        loop {
-            let mut frame_callbacks = 0;
-            let w = &mut unsafe {&mut *self.root}.base;
-            while frame_callbacks < defaults::MAX_FRAME_CALLBACKS {
-                match w.queue().try_recv() {
-                    Ok(mut cmd) => {
-                        if (cmd.as_mut())(unsafe { &mut *self.root } ) {
-                            let _ = w.sender().send(cmd);
-                        }
-                        frame_callbacks += 1;
-                    }
-                    Err(e) => match e {
-                        mpsc::TryRecvError::Empty => break,
-                        mpsc::TryRecvError::Disconnected => unreachable!(),
-                    },
-                }
+        {
+            // `close_root` exits through `prepare_exit` as the quit policy says, this covers starting without roots
+            let app = self.get();
+            if app.base.quit_policy == types::QuitPolicy::OnLastRootClosed && app.base.windows.is_empty() && app.base.trays.is_empty()
+                && controls::Application::prepare_exit(unsafe { &mut *self.root }) {
+                self.exiting = false;
+                return;
             }
-            if self.sleep > 0 {
-                thread::sleep(time::Duration::from_millis(self.sleep as u64));
+        }
+        loop {
+            let frame_callbacks = unsafe { &mut *self.root }.run_frames(defaults::MAX_FRAME_CALLBACKS);
+            unsafe { &mut *self.root }.run_timers();
+            unsafe { &mut *self.root }.run_local();
+            unsafe { &mut *self.root }.run_instance_args();
+            unsafe { &mut *self.root }.run_layout();
+            if frame_callbacks == 0 && !self.get().base.executor.has_woken() {
+                unsafe { &mut *self.root }.call_on_idle();
             }
-            let base = &mut self.get_mut().base; 
-            if base.windows.len() < 1 && base.trays.len() < 1 {
+            if self.exiting {
+                self.exiting = false;
                 break;
             }
+            if self.sleep > 0 && !self.get().base.executor.has_woken() {
+                thread::park_timeout(time::Duration::from_millis(self.sleep as u64));
+            }
         }
     }
-    fn find_member_mut<'a>(&'a mut self, arg: &'a types::FindBy) -> Option<&'a mut dyn controls::Member> {
+    fn find_member_mut<'a>(&'a mut self, arg: types::FindBy<'a>) -> Option<&'a mut dyn controls::Member> {
         let base = &mut self.get_mut().base; 
         for window in base.windows.as_mut_slice() {
-             match arg {
-                types::FindBy::Id(id) => {
-                    if window.id() == *id {
-                        return Some(window.as_member_mut());
-                    }
-                }
-                types::FindBy::Tag(ref tag) => {
-                    if let Some(mytag) = window.tag() {
-                        if tag.as_str() == mytag {
-                            return Some(window.as_member_mut());
-                        }
-                    }
-                }
+            if arg.matches(window.as_member()) {
+                return Some(window.as_member_mut());
             }
             let found = controls::Container::find_control_mut(window.as_mut(), arg).map(|control| control.as_member_mut());
             if found.is_some() {
@@ -163,39 +260,17 @@ impl ApplicationInner for TestableApplication {
             }
         }
         for tray in base.trays.as_mut_slice() {
-        	match arg {
-                types::FindBy::Id(ref id) => {
-                    if tray.id() == *id {
-                        return Some(tray.as_member_mut());
-                    }
-                }
-                types::FindBy::Tag(ref tag) => {
-                    if let Some(mytag) = tray.tag() {
-                        if tag.as_str() == mytag {
-                            return Some(tray.as_member_mut());
-                        }
-                    }
-                }
+            if arg.matches(tray.as_member()) {
+                return Some(tray.as_member_mut());
             }
         }
         None
     }
-    fn find_member<'a>(&'a self, arg: &'a types::FindBy) -> Option<&'a dyn controls::Member> {
+    fn find_member<'a>(&'a self, arg: types::FindBy<'a>) -> Option<&'a dyn controls::Member> {
         let base = &self.get().base; 
         for window in base.windows.as_slice() {
-            match arg {
-                types::FindBy::Id(id) => {
-                    if window.id() == *id {
-                        return Some(window.as_member());
-                    }
-                }
-                types::FindBy::Tag(ref tag) => {
-                    if let Some(mytag) = window.tag() {
-                        if tag.as_str() == mytag {
-                            return Some(window.as_member());
-                        }
-                    }
-                }
+            if arg.matches(window.as_member()) {
+                return Some(window.as_member());
             }
             let found = controls::Container::find_control(window.as_ref(), arg).map(|control| control.as_member());
             if found.is_some() {
@@ -203,24 +278,14 @@ impl ApplicationInner for TestableApplication {
             }
         }
         for tray in base.trays.as_slice() {
-            match arg {
-                types::FindBy::Id(ref id) => {
-                    if tray.id() == *id {
-                        return Some(tray.as_member());
-                    }
-                }
-                types::FindBy::Tag(ref tag) => {
-                    if let Some(mytag) = tray.tag() {
-                        if tag.as_str() == mytag {
-                            return Some(tray.as_member());
-                        }
-                    }
-                }
+            if arg.matches(tray.as_member()) {
+                return Some(tray.as_member());
             }
         }
         None
     }
     fn exit(&mut self) {
+        self.exiting = true;
         let base = &mut self.get_mut().base; 
         for mut window in base.windows.drain(..) {
             window.as_any_mut().downcast_mut::<crate::window::Window>().unwrap().inner_mut().inner_mut().inner_mut().inner_mut().close(true);
@@ -230,10 +295,10 @@ impl ApplicationInner for TestableApplication {
         }
     }
     fn roots<'a>(&'a self) -> Box<dyn Iterator<Item = &'a (dyn controls::Member)> + 'a> {
-        self.get().roots()
+        self.get().base.roots()
     }
     fn roots_mut<'a>(&'a mut self) -> Box<dyn Iterator<Item = &'a mut (dyn controls::Member)> + 'a> {
-        self.get_mut().roots_mut()
+        self.get_mut().base.roots_mut()
     }
 }
 
@@ -244,3 +309,411 @@ impl HasNativeIdInner for TestableApplication {
         (self.root as *mut MemberBase).into()
     }
 }
+
+#[cfg(test)]
+pub(crate) mod tests {
+    use crate::*;
+    use plygui_api::ids::Id;
+    use plygui_api::sdk::HasInner;
+    use plygui_api::{style, types};
+
+    /// A window holding a vertical `LinearLayout` with a `Button`, next to a tray. Returns the ids of the window,
+    /// the tray and the button.
+    pub(crate) fn app_with_tray(name: &str) -> (Box<dyn Application>, Id, Id, Id) {
+        let mut app = imp::Application::with_name(name);
+        let wid = app.new_window::<imp::Window>("w", WindowStartSize::Exact(200, 100), Menu::None);
+        let tid = app.new_tray::<imp::Tray>("t", plygui_api::external::image::DynamicImage::new_rgba8(1, 1), Menu::None);
+        let mut column = imp::LinearLayout::with_orientation(layout::Orientation::Vertical);
+        let button = imp::Button::with_label("ok");
+        let bid = button.id();
+        column.push_child(button.into_control());
+        app.find_member_mut(FindBy::Id(wid)).unwrap().is_window_mut().unwrap().set_child(Some(column.into_control()));
+        (app, wid, tid, bid)
+    }
+
+    #[test]
+    fn traversals_end_after_the_trays() {
+        let (mut app, wid, tid, bid) = app_with_tray("traversal");
+        assert_eq!(app.roots().map(|root| root.id()).collect::<Vec<_>>(), vec![wid, tid]);
+        assert_eq!(app.roots_mut().count(), 2);
+        assert_eq!(app.depth_first().count(), 4);
+        assert_eq!(app.breadth_first().map(|node| node.member.id()).last(), Some(bid));
+        assert_eq!(app.find_members(FindBy::Type("Tray")).len(), 1);
+        assert_eq!(app.find_members(FindBy::Type("Button")).len(), 1);
+        assert!(app.find_member(FindBy::Id(tid)).is_some());
+        assert!(app.close_root(FindBy::Id(wid), true));
+        assert_eq!(app.roots().map(|root| root.id()).collect::<Vec<_>>(), vec![tid]);
+    }
+
+    #[test]
+    fn selectors_match_through_the_ancestors() {
+        let mut app = imp::Application::with_name("selectors");
+        let _ = app.new_window::<imp::Window>("w", WindowStartSize::Exact(200, 100), Menu::None);
+        let wid = app.roots().next().unwrap().id();
+        let mut form = imp::LinearLayout::with_orientation(layout::Orientation::Vertical);
+        form.set_tag(Some("form".into()));
+        let mut button = imp::Button::with_label("ok");
+        button.add_class("primary".into());
+        let bid = button.id();
+        let mut frame = imp::Frame::with_label("f");
+        let nested = imp::Button::with_label("nested");
+        let nid = nested.id();
+        frame.set_child(Some(nested.into_control()));
+        form.push_child(button.into_control());
+        form.push_child(frame.into_control());
+        app.find_member_mut(FindBy::Id(wid)).unwrap().is_window_mut().unwrap().set_child(Some(form.into_control()));
+
+        let matches = |source: &str, id: plygui_api::ids::Id| Selector::parse(source).unwrap().matches(app.find_member(FindBy::Id(id)).unwrap());
+        assert!(matches("Button", bid));
+        assert!(matches("Window Button.primary", bid));
+        assert!(matches("Window > LinearLayout#form > Button", bid));
+        assert!(matches("#form Button", nid));
+        assert!(matches("* > Frame > *", nid));
+        assert!(!matches("#form > Button", nid));
+        assert!(!matches("Button.primary", nid));
+        assert!(!matches("Frame Button", bid));
+        assert!(!matches("LinearLayout#other Button", bid));
+    }
+
+    #[test]
+    fn accessibility_covers_the_trays() {
+        let (mut app, wid, tid, bid) = app_with_tray("a11y");
+        let tree = app.accessibility_tree();
+        assert_eq!(tree.iter().map(|node| node.id).collect::<Vec<_>>(), vec![wid, tid]);
+        assert!(tree[0].nodes().iter().any(|node| node.id == bid));
+        let a = app.as_any_mut().downcast_mut::<imp::Application>().unwrap();
+        assert!(a.inner_mut().lint_accessibility().is_empty());
+    }
+
+
+    #[test]
+    fn stylesheets_restyle_an_application_with_a_tray() {
+        let (mut app, _, _, bid) = app_with_tray("stylesheet");
+        let red = types::Color::rgb(255, 0, 0);
+        let sheet = style::Stylesheet::new()
+            .with_rule("Window Button", style::Style { foreground: Some(red), ..Default::default() })
+            .with_rule("Button.plain", style::Style { foreground: Some(types::Color::BLACK), ..Default::default() });
+        app.set_stylesheet(sheet);
+        assert_eq!(app.find_member(FindBy::Id(bid)).unwrap().is_has_color().unwrap().color(), red);
+        app.find_member_mut(FindBy::Id(bid)).unwrap().add_class("plain".into());
+        app.restyle();
+        assert_eq!(app.find_member(FindBy::Id(bid)).unwrap().is_has_color().unwrap().color(), types::Color::BLACK);
+    }
+
+
+    #[test]
+    fn color_schemes_switch_back_and_forth_with_a_tray() {
+        let (mut app, _, _, bid) = app_with_tray("scheme");
+        let light = types::Color::rgb(20, 20, 20);
+        let dark = types::Color::rgb(230, 230, 230);
+        let sheet = style::Stylesheet::new()
+            .with_rule("Button", style::Style { foreground: Some(light), ..Default::default() })
+            .with_scheme_rule(types::ColorScheme::Dark, "Button", style::Style { foreground: Some(dark), ..Default::default() });
+        app.set_stylesheet(sheet);
+        let color = |app: &dyn Application| app.find_member(FindBy::Id(bid)).unwrap().is_has_color().unwrap().color();
+        assert_eq!(color(app.as_ref()), light);
+
+        let switches = std::rc::Rc::new(std::cell::RefCell::new(Vec::new()));
+        let seen = switches.clone();
+        app.add_on_theme_change((move |_: &mut dyn Application, scheme: types::ColorScheme| seen.borrow_mut().push(scheme)).into());
+        let set_scheme = |app: &mut Box<dyn Application>, scheme| app.as_any_mut().downcast_mut::<imp::Application>().unwrap().inner_mut().set_color_scheme(scheme);
+        set_scheme(&mut app, types::ColorScheme::Dark);
+        assert_eq!(color(app.as_ref()), dark);
+        set_scheme(&mut app, types::ColorScheme::Light);
+        assert_eq!(color(app.as_ref()), light);
+        set_scheme(&mut app, types::ColorScheme::Dark);
+        assert_eq!(app.color_scheme(), types::ColorScheme::Dark);
+        assert_eq!(color(app.as_ref()), dark);
+        assert_eq!(*switches.borrow(), vec![types::ColorScheme::Dark, types::ColorScheme::Light, types::ColorScheme::Dark]);
+    }
+
+
+    #[test]
+    fn locales_relabel_an_application_with_a_tray() {
+        let (mut app, _, _, bid) = app_with_tray("locale");
+        for (locale, text) in [("en", "Confirm"), ("de", "Bestätigen")] {
+            let mut catalog = plygui_api::l10n::Catalog::new(locale);
+            catalog.insert("confirm", text);
+            app.localizer_mut().add_catalog(catalog);
+        }
+        app.find_member_mut(FindBy::Id(bid)).unwrap().is_has_label_mut().unwrap().set_label_key(plygui_api::l10n::Message::new("confirm"));
+        let label = |app: &dyn Application| app.find_member(FindBy::Id(bid)).unwrap().is_has_label().unwrap().label().into_owned();
+        app.set_locale("de");
+        assert_eq!(label(app.as_ref()), "Bestätigen");
+        app.set_locale("en");
+        assert_eq!(label(app.as_ref()), "Confirm");
+    }
+
+
+    #[test]
+    fn layout_dumps_cover_the_trays() {
+        let (app, wid, tid, bid) = app_with_tray("dump");
+        let dumps = app.dump_layout();
+        assert_eq!(dumps.iter().map(|dump| (dump.type_name, dump.id)).collect::<Vec<_>>(), vec![("Window", wid), ("Tray", tid)]);
+        assert_eq!(dumps[0].children[0].children[0].id, bid);
+        assert!(dumps[1].children.is_empty());
+    }
+
+
+    fn foreground(color: types::Color) -> style::Style {
+        style::Style { foreground: Some(color), ..Default::default() }
+    }
+    fn color_of(app: &dyn Application, id: Id) -> types::Color {
+        app.find_member(FindBy::Id(id)).unwrap().is_has_color().unwrap().color()
+    }
+
+    #[test]
+    fn styles_cascade_by_specificity_then_order() {
+        let (mut app, _, _, bid) = app_with_tray("cascade");
+        let (red, green, blue) = (types::Color::rgb(255, 0, 0), types::Color::rgb(0, 255, 0), types::Color::rgb(0, 0, 255));
+        app.find_member_mut(FindBy::Id(bid)).unwrap().add_class("primary".into());
+        // the more specific rule wins even when it comes first
+        app.set_stylesheet(style::Stylesheet::new().with_rule("Button.primary", foreground(red)).with_rule("LinearLayout > Button", foreground(green)));
+        assert_eq!(color_of(app.as_ref(), bid), red);
+        // between equally specific rules the later one wins
+        app.set_stylesheet(style::Stylesheet::new().with_rule("Window Button", foreground(red)).with_rule("LinearLayout Button", foreground(green)));
+        assert_eq!(color_of(app.as_ref(), bid), green);
+        app.set_stylesheet(style::Stylesheet::new().with_rule("LinearLayout Button", foreground(green)).with_rule("Window Button", foreground(red)));
+        assert_eq!(color_of(app.as_ref(), bid), red);
+        // unless the earlier one is limited to the current color scheme
+        app.set_stylesheet(style::Stylesheet::new().with_scheme_rule(types::ColorScheme::Light, "LinearLayout Button", foreground(green)).with_rule("Window Button", foreground(red)));
+        assert_eq!(color_of(app.as_ref(), bid), green);
+        // and the own style wins over all of them
+        app.find_member_mut(FindBy::Id(bid)).unwrap().is_has_color_mut().unwrap().set_color(blue);
+        assert_eq!(color_of(app.as_ref(), bid), blue);
+    }
+
+    #[test]
+    fn foreground_and_font_are_inherited() {
+        let (mut app, wid, _, bid) = app_with_tray("inheritance");
+        let red = types::Color::rgb(255, 0, 0);
+        let serif = types::Font::new("Serif", 14);
+        let sheet = style::Stylesheet::new()
+            .with_rule("LinearLayout", style::Style { foreground: Some(red), font: Some(serif.clone()), background: Some(types::Color::WHITE), ..Default::default() });
+        app.set_stylesheet(sheet);
+        let button = app.find_member(FindBy::Id(bid)).unwrap();
+        assert_eq!(button.is_has_color().unwrap().color(), red);
+        assert_eq!(*button.is_has_font().unwrap().font(), serif);
+        assert_eq!(button.computed_style().background, None);
+
+        // controls added later inherit from the computed style of their new parent
+        let mut frame = imp::Frame::with_label("f");
+        let nested = imp::Text::with_text("nested");
+        let nid = nested.id();
+        frame.set_child(Some(nested.into_control()));
+        let window = app.find_member_mut(FindBy::Id(wid)).unwrap();
+        let column = window.is_container_mut().unwrap().find_control_mut(FindBy::Type("LinearLayout")).unwrap();
+        column.is_container_mut().unwrap().is_multi_container_mut().unwrap().push_child(frame.into_control());
+        assert_eq!(color_of(app.as_ref(), nid), red);
+        assert_eq!(*app.find_member(FindBy::Id(nid)).unwrap().is_has_font().unwrap().font(), serif);
+    }
+
+    #[test]
+    fn unset_styles_restore_the_backend_color_and_font() {
+        let (mut app, _, _, bid) = app_with_tray("unset");
+        let (color, font) = {
+            let button = app.find_member(FindBy::Id(bid)).unwrap();
+            (button.is_has_color().unwrap().color(), button.is_has_font().unwrap().font().into_owned())
+        };
+        let red = types::Color::rgb(255, 0, 0);
+        app.set_stylesheet(style::Stylesheet::new().with_rule("Button", style::Style { foreground: Some(red), font: Some(types::Font::new("Serif", 14)), ..Default::default() }));
+        assert_eq!(color_of(app.as_ref(), bid), red);
+        app.set_stylesheet(style::Stylesheet::new());
+        let button = app.find_member(FindBy::Id(bid)).unwrap();
+        assert_eq!(button.is_has_color().unwrap().color(), color);
+        assert_eq!(*button.is_has_font().unwrap().font(), font);
+    }
+
+
+    #[test]
+    fn removed_classes_clear_their_properties() {
+        let (mut app, _, _, bid) = app_with_tray("classes");
+        let initial = color_of(app.as_ref(), bid);
+        let red = types::Color::rgb(255, 0, 0);
+        let warning = style::Style { foreground: Some(red), background: Some(types::Color::BLACK), padding: Some(layout::BoundarySize::AllTheSame(4)), ..Default::default() };
+        app.set_stylesheet(style::Stylesheet::new().with_rule(".warning", warning.clone()));
+        assert!(app.find_member_mut(FindBy::Id(bid)).unwrap().add_class("warning".into()));
+        app.restyle();
+        assert_eq!(*app.find_member(FindBy::Id(bid)).unwrap().computed_style(), warning);
+        assert_eq!(color_of(app.as_ref(), bid), red);
+
+        assert!(app.find_member_mut(FindBy::Id(bid)).unwrap().remove_class("warning"));
+        app.restyle();
+        assert!(app.find_member(FindBy::Id(bid)).unwrap().computed_style().is_empty());
+        assert_eq!(color_of(app.as_ref(), bid), initial);
+        assert!(!app.find_member_mut(FindBy::Id(bid)).unwrap().remove_class("warning"));
+    }
+
+
+    #[test]
+    fn lifecycle_hooks_fire_in_order() {
+        let (mut app, wid, tid, _) = app_with_tray("lifecycle");
+        app.set_quit_policy(types::QuitPolicy::OnLastWindowClosed);
+        let events = std::rc::Rc::new(std::cell::RefCell::new(Vec::new()));
+        let log = |event: &'static str| {
+            let events = events.clone();
+            move || events.borrow_mut().push(event)
+        };
+        let (start, frame, timer, idle, last, exit) = (log("start"), log("frame"), log("timer"), log("idle"), log("last window closed"), log("before exit"));
+        app.add_on_start((move |_: &mut dyn Application| start()).into());
+        app.on_frame((move |_: &mut dyn Application| { frame(); false }).into());
+        app.add_timer(std::time::Duration::from_secs(0), false, (move |_: &mut dyn Application| timer()).into());
+        app.add_on_idle((move |app: &mut dyn Application| { idle(); assert!(app.close_root(FindBy::Id(wid), true)); }).into());
+        app.add_on_last_window_closed((move |app: &mut dyn Application| { last(); assert!(app.find_member(FindBy::Id(tid)).is_some()); }).into());
+        app.add_on_before_exit((move |_: &mut dyn Application| { exit(); true }).into());
+        app.start();
+        assert_eq!(*events.borrow(), vec!["start", "frame", "timer", "idle", "last window closed", "before exit"]);
+    }
+
+    #[test]
+    fn quit_policies_decide_when_closing_roots_exits() {
+        for (policy, exits_after) in [(types::QuitPolicy::OnLastRootClosed, Some(2)), (types::QuitPolicy::OnLastWindowClosed, Some(1)), (types::QuitPolicy::Explicit, None)] {
+            let (mut app, wid, tid, _) = app_with_tray("quit");
+            app.set_quit_policy(policy);
+            let exits = std::rc::Rc::new(std::cell::Cell::new(0));
+            let counted = exits.clone();
+            app.add_on_before_exit((move |_: &mut dyn Application| { counted.set(counted.get() + 1); false }).into());
+            let mut closed = 0;
+            let mut exited_after = None;
+            for id in [wid, tid] {
+                assert!(app.close_root(FindBy::Id(id), true));
+                closed += 1;
+                if exits.get() > 0 && exited_after.is_none() {
+                    exited_after = Some(closed);
+                }
+            }
+            assert_eq!(exited_after, exits_after, "{:?}", policy);
+            assert_eq!(exits.get(), exits_after.map_or(0, |_| 1), "{:?}", policy);
+        }
+    }
+
+
+    fn dispatch(app: &mut Box<dyn Application>, target: Id) -> bool {
+        app.as_any_mut().downcast_mut::<imp::Application>().unwrap().dispatch_event(target, types::Event::Click)
+    }
+    fn log_events(app: &mut Box<dyn Application>, id: Id, name: &'static str, log: &std::rc::Rc<std::cell::RefCell<Vec<&'static str>>>, stop: bool) -> callbacks::CallbackId {
+        let log = log.clone();
+        app.find_member_mut(FindBy::Id(id)).unwrap().add_on_event((move |_: &mut dyn Member, event: &mut types::BubblingEvent| {
+            log.borrow_mut().push(name);
+            if stop {
+                event.stop_propagation();
+            }
+        }).into())
+    }
+
+    #[test]
+    fn events_bubble_from_the_target_to_the_root() {
+        let (mut app, wid, _, bid) = app_with_tray("bubbling");
+        let cid = app.find_member(FindBy::Type("LinearLayout")).unwrap().id();
+        let log = std::rc::Rc::new(std::cell::RefCell::new(Vec::new()));
+        for (id, name) in [(wid, "window"), (cid, "column"), (bid, "button")] {
+            log_events(&mut app, id, name, &log, false);
+        }
+        assert!(!dispatch(&mut app, bid));
+        assert_eq!(*log.borrow(), vec!["button", "column", "window"]);
+        log.borrow_mut().clear();
+        assert!(!dispatch(&mut app, cid));
+        assert_eq!(*log.borrow(), vec!["column", "window"]);
+    }
+
+    #[test]
+    fn stopped_events_skip_the_parents_but_not_the_other_handlers() {
+        let (mut app, wid, _, bid) = app_with_tray("stopping");
+        let cid = app.find_member(FindBy::Type("LinearLayout")).unwrap().id();
+        let log = std::rc::Rc::new(std::cell::RefCell::new(Vec::new()));
+        log_events(&mut app, wid, "window", &log, false);
+        log_events(&mut app, cid, "column stops", &log, true);
+        log_events(&mut app, cid, "column", &log, false);
+        log_events(&mut app, bid, "button", &log, false);
+        assert!(dispatch(&mut app, bid));
+        assert_eq!(*log.borrow(), vec!["button", "column stops", "column"]);
+    }
+
+    #[test]
+    fn handlers_can_remove_themselves_while_dispatched() {
+        let (mut app, wid, _, bid) = app_with_tray("self removal");
+        let log = std::rc::Rc::new(std::cell::RefCell::new(Vec::new()));
+        let own_id = std::rc::Rc::new(std::cell::Cell::new(None));
+        let (once_log, once_id) = (log.clone(), own_id.clone());
+        let id = app.find_member_mut(FindBy::Id(bid)).unwrap().add_on_event((move |member: &mut dyn Member, _: &mut types::BubblingEvent| {
+            once_log.borrow_mut().push("once");
+            assert!(member.remove_callback(once_id.get().unwrap()));
+        }).into());
+        own_id.set(Some(id));
+        log_events(&mut app, bid, "button", &log, false);
+        log_events(&mut app, wid, "window", &log, false);
+        assert!(!dispatch(&mut app, bid));
+        assert!(!dispatch(&mut app, bid));
+        assert_eq!(*log.borrow(), vec!["once", "button", "window", "button", "window"]);
+        assert!(!app.find_member_mut(FindBy::Id(bid)).unwrap().remove_callback(id));
+    }
+
+
+    #[test]
+    fn dirty_controls_mark_their_ancestors_and_queue_the_topmost_once() {
+        let (mut app, _, _, bid) = app_with_tray("dirty");
+        let cid = app.find_member(FindBy::Type("LinearLayout")).unwrap().id();
+        let queued = |app: &mut Box<dyn Application>| app.as_any_mut().downcast_mut::<imp::Application>().unwrap().queued_layout();
+        app.as_any_mut().downcast_mut::<imp::Application>().unwrap().run_layout();
+        assert!(queued(&mut app).is_empty());
+        let cached = |app: &dyn Application, id: Id| {
+            let control = app.find_member(FindBy::Id(id)).unwrap().is_control().unwrap();
+            let cache = match control.as_any().downcast_ref::<imp::LinearLayout>() {
+                Some(column) => column.inner().base.measure_cache,
+                None => control.as_any().downcast_ref::<imp::Button>().unwrap().inner().base.measure_cache,
+            };
+            (control.is_dirty(), cache.is_some())
+        };
+        assert_eq!(cached(app.as_ref(), cid), (false, true));
+        assert_eq!(cached(app.as_ref(), bid), (false, true));
+
+        app.find_member_mut(FindBy::Id(bid)).unwrap().is_control_mut().unwrap().mark_dirty();
+        assert_eq!(cached(app.as_ref(), bid), (true, false));
+        assert_eq!(cached(app.as_ref(), cid), (true, false));
+        assert_eq!(queued(&mut app), vec![cid]);
+        app.find_member_mut(FindBy::Id(cid)).unwrap().is_control_mut().unwrap().mark_dirty();
+        assert_eq!(queued(&mut app), vec![cid]);
+
+        app.as_any_mut().downcast_mut::<imp::Application>().unwrap().run_layout();
+        assert!(queued(&mut app).is_empty());
+        assert_eq!(cached(app.as_ref(), bid), (false, true));
+        assert_eq!(cached(app.as_ref(), cid), (false, true));
+    }
+
+
+    #[test]
+    fn later_instances_report_the_forwarding_instead_of_exiting() {
+        let name = format!("plygui-testable-forwarding-{}", std::process::id());
+        let app = imp::Application::with_name_single_instance(name.as_str());
+        assert!(app.is_ok());
+        let forwarded = std::thread::spawn(move || imp::Application::with_name_single_instance(name.as_str()).err()).join().unwrap();
+        assert_eq!(forwarded, Some(plygui_api::Error::Forwarded));
+    }
+
+
+    #[test]
+    fn scale_factors_scale_the_physical_size_only() {
+        let (mut app, wid, _, bid) = app_with_tray("scale");
+        {
+            let button = app.find_member_mut(FindBy::Id(bid)).unwrap().is_control_mut().unwrap();
+            button.set_layout_width(layout::Size::WrapContent);
+            button.set_layout_height(layout::Size::WrapContent);
+        }
+        let sizes = |app: &mut Box<dyn Application>| {
+            app.as_any_mut().downcast_mut::<imp::Application>().unwrap().run_layout();
+            let window = app.find_member(FindBy::Id(wid)).unwrap().is_window().unwrap();
+            let button = &app.find_member(FindBy::Id(bid)).unwrap().as_any().downcast_ref::<imp::Button>().unwrap().inner().inner().inner().base;
+            (window.scale_factor(), window.size(), button.size, button.physical_size)
+        };
+        let (scale, window, logical, physical) = sizes(&mut app);
+        assert_eq!((scale, window), (1.0, (200, 100)));
+        assert_eq!(physical, logical);
+
+        app.as_any_mut().downcast_mut::<imp::Application>().unwrap().inner_mut().set_scale_factor(2.0);
+        // the native window keeps its pixels, so it gets smaller in logical ones, while the controls keep their logical size
+        assert_eq!(sizes(&mut app), (2.0, (100, 50), logical, (logical.0 * 2, logical.1 * 2)));
+
+        app.as_any_mut().downcast_mut::<imp::Application>().unwrap().inner_mut().set_scale_factor(1.0);
+        assert_eq!(sizes(&mut app), (1.0, (200, 100), logical, logical));
+    }
+}
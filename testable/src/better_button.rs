@@ -17,10 +17,33 @@ impl HasLabelInner for TestableBetterButton {
     }
 }
 
+impl HasFontInner for TestableBetterButton {
+    fn font(&self, base: &MemberBase) -> Cow<'_, types::Font> {
+        self.inner.font(base)
+    }
+    fn set_font(&mut self, base: &mut MemberBase, font: Cow<types::Font>) {
+        self.inner.set_font(base, font)
+    }
+}
+impl HasColorInner for TestableBetterButton {
+    fn color(&self, base: &MemberBase) -> types::Color {
+        self.inner.color(base)
+    }
+    fn set_color(&mut self, base: &mut MemberBase, color: types::Color) {
+        self.inner.set_color(base, color)
+    }
+}
+
 impl ClickableInner for TestableBetterButton {
     fn on_click(&mut self, handle: Option<callbacks::OnClick>) {
         self.inner.on_click(handle)
     }
+    fn add_on_click(&mut self, handle: callbacks::OnClick) -> callbacks::CallbackId {
+        self.inner.add_on_click(handle)
+    }
+    fn remove_on_click(&mut self, id: callbacks::CallbackId) -> bool {
+        self.inner.remove_on_click(id)
+    }
     fn click(&mut self, skip_callbacks: bool) {
         self.inner.click(skip_callbacks)
     }
@@ -29,7 +52,7 @@ impl ClickableInner for TestableBetterButton {
 impl ButtonInner for TestableBetterButton {
     fn with_label<S: AsRef<str>>(label: S) -> Box<dyn controls::Button> {
     	let mut b: Box<mem::MaybeUninit<BetterButton>> = Box::new_uninit();
-        let mut ab = AMember::with_inner(
+        let ab = AMember::with_inner(
             AControl::with_inner(
                 AButton::with_inner(
                     TestableBetterButton {
@@ -38,11 +61,12 @@ impl ButtonInner for TestableBetterButton {
                 ),
             )
         );
-        controls::HasLabel::set_label(&mut ab, label.as_ref().into());
-        unsafe {
+        let mut b = unsafe {
 	        b.as_mut_ptr().write(ab);
 	        b.assume_init()
-        }
+        };
+        controls::HasLabel::set_label(b.as_mut(), label.as_ref().into());
+        b
     }
 }
 impl Spawnable for TestableBetterButton {
@@ -57,6 +81,9 @@ impl ControlInner for TestableBetterButton {
     fn on_removed_from_container(&mut self, member: &mut MemberBase, control: &mut ControlBase, parent: &dyn controls::Container) {
 	    self.inner.on_removed_from_container(member, control, parent)
     }
+    fn on_style_changed(&mut self, member: &mut MemberBase, control: &mut ControlBase, style: &style::Style) {
+        self.inner.on_style_changed(member, control, style)
+    }
     fn parent(&self) -> Option<&dyn controls::Member> {
         self.inner.parent()
     }
@@ -71,11 +98,14 @@ impl ControlInner for TestableBetterButton {
     }
 
     #[cfg(feature = "markup")]
-    fn fill_from_markup(&mut self, member: &mut MemberBase, _control: &mut ControlBase, markup: &plygui_api::markup::Markup, registry: &mut plygui_api::markup::MarkupRegistry) {
+    fn fill_from_markup(&mut self, member: &mut MemberBase, _control: &mut ControlBase, markup: &plygui_api::markup::Markup, registry: &mut plygui_api::markup::MarkupRegistry) -> Result<(), plygui_api::Error> {
         use plygui_api::markup::MEMBER_TYPE_BUTTON;
         fill_from_markup_base!(self, member, markup, registry, Button, [MEMBER_TYPE_BUTTON]);
         fill_from_markup_label!(self, member, markup);
+        fill_from_markup_font!(self, member, markup);
+        fill_from_markup_color!(self, member, markup);
         fill_from_markup_callbacks!(self, markup, registry, [on_click => plygui_api::callbacks::OnClick]);
+        Ok(())
     }
 }
 
@@ -115,7 +145,11 @@ impl HasVisibilityInner for TestableBetterButton {
     }
 }
 
-impl MemberInner for TestableBetterButton {}
+impl MemberInner for TestableBetterButton {
+    fn on_accessibility_changed(&mut self, base: &mut MemberBase, name: Option<&str>, description: Option<&str>, role: a11y::Role) {
+        common::trace_accessibility(base, name, description, role);
+    }
+}
 
 impl Drawable for TestableBetterButton {
     fn draw(&mut self, member: &mut MemberBase, control: &mut ControlBase) {
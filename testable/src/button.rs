@@ -6,14 +6,18 @@ pub type Button = AMember<AControl<AButton<TestableButton>>>;
 pub struct TestableButton {
     pub base: common::TestableControlBase<Button>,
     label: String,
-    h_left_clicked: Option<callbacks::OnClick>,
+    font: types::Font,
+    color: types::Color,
+    h_left_clicked: callbacks::CallbackList<callbacks::OnClick>,
 }
 impl<O: controls::Button> NewButtonInner<O> for TestableButton {
     fn with_uninit(u: &mut mem::MaybeUninit<O>) -> Self {
         TestableButton {
 	        base: common::TestableControlBase::with_id(u),
-	        h_left_clicked: None,
+	        h_left_clicked: callbacks::CallbackList::new(),
 	        label: String::new(),
+	        font: Default::default(),
+	        color: types::Color::BLACK,
         }
     }
 }
@@ -27,15 +31,43 @@ impl HasLabelInner for TestableButton {
     }
 }
 
+impl HasFontInner for TestableButton {
+    fn font(&self, _base: &MemberBase) -> Cow<'_, types::Font> {
+        Cow::Borrowed(&self.font)
+    }
+    fn set_font(&mut self, _base: &mut MemberBase, font: Cow<types::Font>) {
+        self.font = font.into_owned();
+        self.base.invalidate();
+    }
+}
+impl HasColorInner for TestableButton {
+    fn color(&self, _base: &MemberBase) -> types::Color {
+        self.color
+    }
+    fn set_color(&mut self, _base: &mut MemberBase, color: types::Color) {
+        self.color = color;
+        common::trace_backend(self.base.id, format_args!("color {}", color));
+    }
+}
+
 impl ClickableInner for TestableButton {
     fn on_click(&mut self, handle: Option<callbacks::OnClick>) {
-        self.h_left_clicked = handle;
+        self.h_left_clicked.set(handle);
+    }
+    fn add_on_click(&mut self, handle: callbacks::OnClick) -> callbacks::CallbackId {
+        self.h_left_clicked.add(handle)
+    }
+    fn remove_on_click(&mut self, id: callbacks::CallbackId) -> bool {
+        self.h_left_clicked.remove(id)
     }
     fn click(&mut self, skip_callbacks: bool) {
         if !skip_callbacks {
-            if let Some(ref mut h_left_clicked) = self.h_left_clicked {
+            let mut callbacks = self.h_left_clicked.take();
+            for h_left_clicked in callbacks.iter_mut() {
+                common::trace_callback(self.base.id, h_left_clicked);
                 (h_left_clicked.as_mut())(unsafe { &mut *(self.base.id as *mut Button) });
             }
+            self.h_left_clicked.restore(callbacks);
         }
     }
 }
@@ -43,18 +75,19 @@ impl ClickableInner for TestableButton {
 impl ButtonInner for TestableButton {
     fn with_label<S: AsRef<str>>(label: S) -> Box<dyn controls::Button> {
     	let mut b: Box<mem::MaybeUninit<Button>> = Box::new_uninit();
-        let mut ab = AMember::with_inner(
+        let ab = AMember::with_inner(
             AControl::with_inner(
                 AButton::with_inner(
                     <Self as NewButtonInner<Button>>::with_uninit(b.as_mut())
                 ),
             )
         );
-        controls::HasLabel::set_label(&mut ab, label.as_ref().into());
-        unsafe {
+        let mut b = unsafe {
 	        b.as_mut_ptr().write(ab);
 	        b.assume_init()
-        }
+        };
+        controls::HasLabel::set_label(b.as_mut(), label.as_ref().into());
+        b
     }
 }
 impl Spawnable for TestableButton {
@@ -70,6 +103,9 @@ impl ControlInner for TestableButton {
     fn on_removed_from_container(&mut self, _member: &mut MemberBase, _control: &mut ControlBase, _: &dyn controls::Container) {
 	    self.base.parent = None;
     }
+    fn on_style_changed(&mut self, _member: &mut MemberBase, _control: &mut ControlBase, style: &style::Style) {
+        self.base.on_style_changed(style);
+    }
     fn parent(&self) -> Option<&dyn controls::Member> {
         self.base.parent().map(|p| p.as_member())
     }
@@ -84,18 +120,19 @@ impl ControlInner for TestableButton {
     }
 
     #[cfg(feature = "markup")]
-    fn fill_from_markup(&mut self, member: &mut MemberBase, _control: &mut ControlBase, markup: &plygui_api::markup::Markup, registry: &mut plygui_api::markup::MarkupRegistry) {
+    fn fill_from_markup(&mut self, member: &mut MemberBase, _control: &mut ControlBase, markup: &plygui_api::markup::Markup, registry: &mut plygui_api::markup::MarkupRegistry) -> Result<(), plygui_api::Error> {
         use plygui_api::markup::MEMBER_TYPE_BUTTON;
         fill_from_markup_base!(self, member, markup, registry, Button, [MEMBER_TYPE_BUTTON]);
         fill_from_markup_label!(self, member, markup);
+        fill_from_markup_font!(self, member, markup);
+        fill_from_markup_color!(self, member, markup);
         fill_from_markup_callbacks!(self, markup, registry, [on_click => plygui_api::callbacks::OnClick]);
+        Ok(())
     }
 }
 
 impl HasLayoutInner for TestableButton {
-    fn on_layout_changed(&mut self, _base: &mut MemberBase) {
-        self.base.invalidate();
-    }
+    fn on_layout_changed(&mut self, _base: &mut MemberBase) {}
 }
 
 impl HasNativeIdInner for TestableButton {
@@ -113,7 +150,6 @@ impl HasSizeInner for TestableButton {
         let this = base.as_any_mut().downcast_mut::<Button>().unwrap();
         this.set_layout_width(layout::Size::Exact(width));
         this.set_layout_width(layout::Size::Exact(height));
-        self.base.invalidate();
         
         unsafe { utils::base_to_impl_mut::<Button>(base) }.call_on_size::<Button>(width, height);
         
@@ -127,7 +163,11 @@ impl HasVisibilityInner for TestableButton {
     }
 }
 
-impl MemberInner for TestableButton {}
+impl MemberInner for TestableButton {
+    fn on_accessibility_changed(&mut self, base: &mut MemberBase, name: Option<&str>, description: Option<&str>, role: a11y::Role) {
+        common::trace_accessibility(base, name, description, role);
+    }
+}
 
 impl Drawable for TestableButton {
     fn draw(&mut self, _member: &mut MemberBase, control: &mut ControlBase) {
@@ -160,6 +200,39 @@ impl Drawable for TestableButton {
         (control.measured.0, control.measured.1, control.measured != old_size)
     }
     fn invalidate(&mut self, _member: &mut MemberBase, _control: &mut ControlBase) {
-        self.base.invalidate()
+        self.base.relayout()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+    use plygui_api::sdk::{HasInner, OuterDrawable};
+
+    /// Changes the label behind the back of the layout, so only a new measure can see it.
+    fn relabel(button: &mut super::Button, label: &str) {
+        button.inner_mut().inner_mut().inner_mut().label = label.into();
+    }
+
+    #[test]
+    fn measures_are_reused_until_the_constraints_change_or_it_gets_dirty() {
+        let _app = imp::Application::with_name("measure cache");
+        let mut button = imp::Button::with_label("ok");
+        let button = button.as_any_mut().downcast_mut::<super::Button>().unwrap();
+        button.set_layout_width(layout::Size::WrapContent);
+        let (w, h, _) = button.measure(100, 100);
+        assert!(!button.is_dirty());
+
+        relabel(button, "a longer label");
+        assert_eq!(button.measure(100, 100), (w, h, false));
+        let (longer, _, changed) = button.measure(90, 100);
+        assert!(longer > w && changed);
+
+        relabel(button, "ok");
+        assert_eq!(button.measure(90, 100), (longer, h, false));
+        button.mark_dirty();
+        assert!(button.is_dirty());
+        assert_eq!(button.inner().base.measure_cache, None);
+        assert_eq!(button.measure(90, 100), (w, h, true));
     }
 }
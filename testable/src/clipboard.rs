@@ -0,0 +1,86 @@
+use crate::common::*;
+
+use std::any::Any;
+
+#[derive(Default)]
+pub struct TestableClipboard {
+    text: Option<String>,
+    image: Option<image::DynamicImage>,
+}
+
+impl types::Clipboard for TestableClipboard {
+    fn formats(&self) -> Vec<types::ClipboardFormat> {
+        let mut formats = Vec::with_capacity(2);
+        if self.text.is_some() {
+            formats.push(types::ClipboardFormat::Text);
+        }
+        if self.image.is_some() {
+            formats.push(types::ClipboardFormat::Image);
+        }
+        formats
+    }
+    fn text(&self) -> Option<String> {
+        self.text.clone()
+    }
+    fn set_text(&mut self, text: Cow<str>) {
+        self.image = None;
+        self.text = Some(text.into());
+    }
+    fn image(&self) -> Option<image::DynamicImage> {
+        self.image.clone()
+    }
+    fn set_image(&mut self, image: Cow<image::DynamicImage>) {
+        self.text = None;
+        self.image = Some(image.into_owned());
+    }
+    fn clear(&mut self) {
+        self.text = None;
+        self.image = None;
+    }
+}
+
+impl types::AsAny for TestableClipboard {
+    #[inline]
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+    #[inline]
+    fn as_any_mut(&mut self) -> &mut dyn Any {
+        self
+    }
+    #[inline]
+    fn into_any(self: Box<Self>) -> Box<dyn Any> {
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+    use plygui_api::external::image::{DynamicImage, GenericImageView};
+    use plygui_api::types::ClipboardFormat;
+
+    #[test]
+    fn clipboard_holds_the_last_text_or_image() {
+        let mut app = imp::Application::with_name("clipboard");
+        assert!(app.clipboard().formats().is_empty());
+        assert_eq!(app.clipboard().text(), None);
+
+        app.clipboard_mut().set_text("copied".into());
+        assert_eq!(app.clipboard().formats(), vec![ClipboardFormat::Text]);
+        assert_eq!(app.clipboard().text().as_deref(), Some("copied"));
+        assert!(!app.clipboard().has_format(ClipboardFormat::Image));
+
+        let image = DynamicImage::new_rgba8(2, 3);
+        app.clipboard_mut().set_image(std::borrow::Cow::Borrowed(&image));
+        assert_eq!(app.clipboard().formats(), vec![ClipboardFormat::Image]);
+        assert_eq!(app.clipboard().text(), None);
+        assert_eq!(app.clipboard().image().map(|image| (image.width(), image.height())), Some((2, 3)));
+
+        app.clipboard_mut().set_text("again".into());
+        assert_eq!(app.clipboard().image(), None);
+        app.clipboard_mut().clear();
+        assert!(app.clipboard().formats().is_empty());
+        assert!(!app.clipboard().has_format(ClipboardFormat::Text));
+    }
+}
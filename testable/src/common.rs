@@ -1,5 +1,5 @@
 pub use plygui_api::sdk::*;
-pub use plygui_api::{callbacks, controls, defaults, ids, layout, types::{self, adapter}, utils};
+pub use plygui_api::{a11y, callbacks, controls, defaults, ids, layout, style, types::{self, adapter}, utils};
 pub use plygui_api::external::image;
 
 pub use std::borrow::Cow;
@@ -53,6 +53,11 @@ pub struct TestableControlBase<T: controls::Control + Sized> {
     pub size: (u16, u16),
     pub position: (i32, i32),
     pub visibility: types::Visibility,
+    /// `size` and `position` in physical pixels, as of the last draw.
+    pub physical_size: (u16, u16),
+    pub physical_position: (i32, i32),
+    /// The last style handed over by `on_style_changed`, `None` until the control gets styled.
+    pub style: Option<style::Style>,
     _marker: PhantomData<T>,
 }
 
@@ -65,6 +70,9 @@ impl<T: controls::Control + Sized> TestableControlBase<T> {
 		    position: (0, 0),
 		    parent: None,
             visibility: types::Visibility::Visible,
+            physical_size: (0, 0),
+            physical_position: (0, 0),
+            style: None,
             _marker: PhantomData,
         }
     }
@@ -75,6 +83,9 @@ impl<T: controls::Control + Sized> TestableControlBase<T> {
 		    position: (0, 0),
 		    parent: None,
             visibility: types::Visibility::Visible,
+            physical_size: (0, 0),
+            physical_position: (0, 0),
+            style: None,
             _marker: PhantomData,
         }
     }
@@ -119,7 +130,20 @@ impl<T: controls::Control + Sized> TestableControlBase<T> {
     pub fn as_outer_mut(&self) -> &mut T {
         member_from_id::<T>(self.id.into()).unwrap()
     }
+    /// Records the resolved style, so tests can assert what the control was given.
+    pub fn on_style_changed(&mut self, style: &style::Style) {
+        trace_backend(self.id, format_args!("style {:?}", style));
+        self.style = Some(style.clone());
+    }
+    /// Marks the control dirty, it gets relaid out with the next layout pass.
     pub fn invalidate(&mut self) {
+        if self.id.is_null() {
+            return;
+        }
+        self.as_outer_mut().mark_dirty();
+    }
+    /// Measures the control against its parent right away, invalidating the parent if the size changed.
+    pub fn relayout(&mut self) {
         if self.id.is_null() {
             return;
         }
@@ -161,10 +185,10 @@ impl<T: controls::Control + Sized> TestableControlBase<T> {
     	if let Some(coords) = coords {
     		self.position = coords;
     	}
-    	for _ in 0..self.parent_offset() {
-    		print!("-");
-    	}
-        println!("{} drawn ({} px, {} px) at {:?} ({:?})", name, self.size.0, self.size.1, self.position, self.id);
+    	let scale = if self.id.is_null() { 1.0 } else { utils::scale_factor(self.as_outer().as_member()) };
+    	self.physical_size = (utils::coord_to_size(layout::to_physical(size.0 as i32, scale)), utils::coord_to_size(layout::to_physical(size.1 as i32, scale)));
+    	self.physical_position = (layout::to_physical(self.position.0, scale), layout::to_physical(self.position.1, scale));
+        trace_backend(self.id, format_args!("{} drawn ({} px, {} px) at {:?}, physical {:?} at {:?}, depth {}", name, self.size.0, self.size.1, self.position, self.physical_size, self.physical_position, self.parent_offset()));
         true
     }
     pub fn on_set_visibility(&mut self, visibility: types::Visibility) -> bool {
@@ -196,6 +220,22 @@ pub fn member_base_from_id<'a>(id: InnerId) -> Option<&'a mut MemberBase> {
     unsafe { cast_id(id) }
 }
 
+/// Reports what the backend did with the member behind `id`, see `plygui_api::trace`.
+pub fn trace_backend(id: InnerId, message: std::fmt::Arguments) {
+    if let Some(base) = member_base_from_id(id) {
+        plygui_api::trace::backend(base, message);
+    }
+}
+/// Reports the accessibility properties handed to the backend, see `MemberInner::on_accessibility_changed`.
+pub fn trace_accessibility(base: &MemberBase, name: Option<&str>, description: Option<&str>, role: a11y::Role) {
+    plygui_api::trace::backend(base, format_args!("accessible {:?} {:?} ({:?})", role, name, description));
+}
+pub fn trace_callback<C: callbacks::Callback>(id: InnerId, callback: &C) {
+    if let Some(base) = member_base_from_id(id) {
+        plygui_api::trace::callback(Some(base), callback);
+    }
+}
+
 /*pub unsafe fn make_menu(menu: windef::HMENU, mut items: Vec<types::MenuItem>, storage: &mut Vec<callbacks::Action>) {
     let mut options = Vec::new();
     let mut help = Vec::new();
@@ -0,0 +1,129 @@
+use crate::common::{self, *};
+
+use std::cell::RefCell;
+use std::collections::VecDeque;
+use std::path::PathBuf;
+
+thread_local! {
+    static RESPONSES: RefCell<VecDeque<Result<Vec<PathBuf>, ()>>> = const { RefCell::new(VecDeque::new()) };
+}
+
+#[repr(C)]
+pub struct TestableFileDialog {
+    id: common::InnerId,
+    parent: Option<ids::Id>,
+    label: String,
+    mode: types::FileDialogMode,
+    filters: Vec<types::FileFilter>,
+    default_path: Option<PathBuf>,
+    multiple: bool,
+}
+
+pub type FileDialog = AMember<AFileDialog<TestableFileDialog>>;
+
+impl TestableFileDialog {
+    /// Queues the result the next started dialog will return, in place of user input. `Err(())` cancels it,
+    /// as does starting a dialog with nothing queued.
+    pub fn push_response(response: Result<Vec<PathBuf>, ()>) {
+        RESPONSES.with(|r| r.borrow_mut().push_back(response));
+    }
+    pub fn clear_responses() {
+        RESPONSES.with(|r| r.borrow_mut().clear());
+    }
+    pub fn filters(&self) -> &[types::FileFilter] {
+        self.filters.as_slice()
+    }
+    pub fn default_path(&self) -> Option<&PathBuf> {
+        self.default_path.as_ref()
+    }
+    pub fn is_multiple(&self) -> bool {
+        self.multiple
+    }
+}
+
+impl HasLabelInner for TestableFileDialog {
+    fn label(&self, _base: &MemberBase) -> Cow<'_, str> {
+        Cow::Borrowed(self.label.as_ref())
+    }
+    fn set_label(&mut self, _base: &mut MemberBase, label: Cow<str>) {
+        self.label = label.into();
+    }
+}
+
+impl FileDialogInner for TestableFileDialog {
+    fn with_params(title: &str, mode: types::FileDialogMode, filters: Vec<types::FileFilter>, default_path: Option<PathBuf>, multiple: bool, parent: Option<&dyn controls::Member>) -> Box<dyn controls::FileDialog> {
+        let a: Box<FileDialog> = Box::new(AMember::with_inner(
+            AFileDialog::with_inner(
+                TestableFileDialog {
+                    id: ptr::null_mut(),
+                    parent: parent.map(|p|p.id()),
+                    label: title.into(),
+                    mode,
+                    filters,
+                    default_path,
+                    multiple,
+                }
+            )
+        ));
+        a
+    }
+    fn mode(&self) -> types::FileDialogMode {
+        self.mode
+    }
+    fn start(self) -> Result<Vec<PathBuf>, ()> {
+        common::trace_backend(self.id, format_args!("{:?} file dialog '{}' started", self.mode, self.label));
+        let response = RESPONSES.with(|r| r.borrow_mut().pop_front());
+        match response {
+            Some(Ok(mut paths)) => {
+                if !self.multiple {
+                    paths.truncate(1);
+                }
+                Ok(paths)
+            }
+            Some(Err(())) | None => Err(()),
+        }
+    }
+}
+
+impl HasNativeIdInner for TestableFileDialog {
+    type Id = common::TestableId;
+
+    fn native_id(&self) -> Self::Id {
+        self.id.into()
+    }
+}
+
+impl MemberInner for TestableFileDialog {
+    fn on_accessibility_changed(&mut self, base: &mut MemberBase, name: Option<&str>, description: Option<&str>, role: a11y::Role) {
+        common::trace_accessibility(base, name, description, role);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+    use plygui_api::types;
+    use std::path::PathBuf;
+
+    fn open(multiple: bool) -> Box<dyn FileDialog> {
+        imp::FileDialog::with_params("Open", types::FileDialogMode::Open, vec![], Some(PathBuf::from("/default")), multiple, None)
+    }
+
+    #[test]
+    fn start_returns_the_queued_answer() {
+        TestableFileDialog::clear_responses();
+        let paths = vec![PathBuf::from("/a"), PathBuf::from("/b")];
+        TestableFileDialog::push_response(Ok(paths.clone()));
+        TestableFileDialog::push_response(Ok(paths.clone()));
+        assert_eq!(open(true).start(), Ok(paths));
+        assert_eq!(open(false).start(), Ok(vec![PathBuf::from("/a")]));
+    }
+
+    #[test]
+    fn start_is_cancelled_without_an_answer() {
+        TestableFileDialog::clear_responses();
+        TestableFileDialog::push_response(Err(()));
+        assert_eq!(open(false).start(), Err(()));
+        assert_eq!(open(false).start(), Err(()));
+    }
+}
@@ -7,6 +7,8 @@ pub struct TestableFrame {
     base: common::TestableControlBase<Frame>,
     label: String,
     label_padding: i32,
+    font: types::Font,
+    color: types::Color,
     child: Option<Box<dyn controls::Control>>,
 }
 impl<O: controls::Frame> NewFrameInner<O> for TestableFrame {
@@ -16,13 +18,15 @@ impl<O: controls::Frame> NewFrameInner<O> for TestableFrame {
             child: None,
             label: String::new(),
             label_padding: 0,
+            font: Default::default(),
+            color: types::Color::BLACK,
         }
     }
 }
 impl FrameInner for TestableFrame {
     fn with_label<S: AsRef<str>>(label: S) -> Box<dyn controls::Frame> {
         let mut b: Box<mem::MaybeUninit<Frame>> = Box::new_uninit();
-        let mut ab = AMember::with_inner(
+        let ab = AMember::with_inner(
             AControl::with_inner(
                 AContainer::with_inner(
                     ASingleContainer::with_inner(
@@ -33,11 +37,12 @@ impl FrameInner for TestableFrame {
                 ),
             )
         );
-        controls::HasLabel::set_label(&mut ab, label.as_ref().into());
-        unsafe {
+        let mut b = unsafe {
 	        b.as_mut_ptr().write(ab);
 	        b.assume_init()
-        }
+        };
+        controls::HasLabel::set_label(b.as_mut(), label.as_ref().into());
+        b
     }
 }
 impl Spawnable for TestableFrame {
@@ -46,9 +51,7 @@ impl Spawnable for TestableFrame {
     }
 }
 impl HasLayoutInner for TestableFrame {
-    fn on_layout_changed(&mut self, _base: &mut MemberBase) {
-        self.base.invalidate();
-    }
+    fn on_layout_changed(&mut self, _base: &mut MemberBase) {}
     fn layout_margin(&self, _member: &MemberBase) -> layout::BoundarySize {
         layout::BoundarySize::Distinct(DEFAULT_PADDING, DEFAULT_PADDING + self.label_padding, DEFAULT_PADDING, DEFAULT_PADDING)
     }
@@ -58,9 +61,28 @@ impl HasLabelInner for TestableFrame {
     fn label(&self, _base: &MemberBase) -> Cow<str> {
         Cow::Borrowed(self.label.as_ref())
     }
-    fn set_label(&mut self, base: &mut MemberBase, label: Cow<str>) {
+    fn set_label(&mut self, _base: &mut MemberBase, label: Cow<str>) {
         self.label = label.into();
-        self.on_layout_changed(base);
+        self.base.invalidate();
+    }
+}
+
+impl HasFontInner for TestableFrame {
+    fn font(&self, _base: &MemberBase) -> Cow<'_, types::Font> {
+        Cow::Borrowed(&self.font)
+    }
+    fn set_font(&mut self, _base: &mut MemberBase, font: Cow<types::Font>) {
+        self.font = font.into_owned();
+        self.base.invalidate();
+    }
+}
+impl HasColorInner for TestableFrame {
+    fn color(&self, _base: &MemberBase) -> types::Color {
+        self.color
+    }
+    fn set_color(&mut self, _base: &mut MemberBase, color: types::Color) {
+        self.color = color;
+        common::trace_backend(self.base.id, format_args!("color {}", color));
     }
 }
 
@@ -105,21 +127,10 @@ impl SingleContainerInner for TestableFrame {
 }
 
 impl ContainerInner for TestableFrame {
-    fn find_control_mut<'a>(&'a mut self, arg: &'a types::FindBy) -> Option<&'a mut dyn controls::Control> {
+    fn find_control_mut<'a>(&'a mut self, arg: types::FindBy<'a>) -> Option<&'a mut dyn controls::Control> {
         if let Some(child) = self.child.as_mut() {
-            match arg {
-                types::FindBy::Id(id) => {
-                    if child.as_member_mut().id() == *id {
-                        return Some(child.as_mut());
-                    }
-                }
-                types::FindBy::Tag(ref tag) => {
-                    if let Some(mytag) = child.as_member_mut().tag() {
-                        if tag.as_str() == mytag {
-                            return Some(child.as_mut());
-                        }
-                    }
-                }
+            if arg.matches(child.as_member()) {
+                return Some(child.as_mut());
             }
             if let Some(c) = child.is_container_mut() {
                 c.find_control_mut(arg)
@@ -130,21 +141,10 @@ impl ContainerInner for TestableFrame {
             None
         }
     }
-    fn find_control<'a>(&'a self, arg: &'a types::FindBy) -> Option<&'a dyn controls::Control> {
+    fn find_control<'a>(&'a self, arg: types::FindBy<'a>) -> Option<&'a dyn controls::Control> {
         if let Some(child) = self.child.as_ref() {
-            match arg {
-                types::FindBy::Id(id) => {
-                    if child.as_member().id() == *id {
-                        return Some(child.as_ref());
-                    }
-                }
-                types::FindBy::Tag(ref tag) => {
-                    if let Some(mytag) = child.as_member().tag() {
-                        if tag.as_str() == mytag {
-                            return Some(child.as_ref());
-                        }
-                    }
-                }
+            if arg.matches(child.as_member()) {
+                return Some(child.as_ref());
             }
             if let Some(c) = child.is_container() {
                 c.find_control(arg)
@@ -180,6 +180,9 @@ impl ControlInner for TestableFrame {
         }
         self.base.parent = None;
     }
+    fn on_style_changed(&mut self, _member: &mut MemberBase, _control: &mut ControlBase, style: &style::Style) {
+        self.base.on_style_changed(style);
+    }
 
     fn parent(&self) -> Option<&dyn controls::Member> {
         self.base.parent().map(|p| p.as_member())
@@ -195,12 +198,15 @@ impl ControlInner for TestableFrame {
     }
 
     #[cfg(feature = "markup")]
-    fn fill_from_markup(&mut self, member: &mut MemberBase, _control: &mut ControlBase, markup: &plygui_api::markup::Markup, registry: &mut plygui_api::markup::MarkupRegistry) {
+    fn fill_from_markup(&mut self, member: &mut MemberBase, _control: &mut ControlBase, markup: &plygui_api::markup::Markup, registry: &mut plygui_api::markup::MarkupRegistry) -> Result<(), plygui_api::Error> {
         use plygui_api::markup::MEMBER_TYPE_FRAME;
 
         fill_from_markup_base!(self, member, markup, registry, Frame, [MEMBER_TYPE_FRAME]);
         fill_from_markup_label!(self, member, markup);
+        fill_from_markup_font!(self, member, markup);
+        fill_from_markup_color!(self, member, markup);
         fill_from_markup_child!(self, member, markup, registry);
+        Ok(())
     }
 }
 
@@ -219,7 +225,6 @@ impl HasSizeInner for TestableFrame {
         let this = base.as_any_mut().downcast_mut::<Frame>().unwrap();
         this.set_layout_width(layout::Size::Exact(width));
         this.set_layout_width(layout::Size::Exact(height));
-        self.base.invalidate();
         
         unsafe { utils::base_to_impl_mut::<Frame>(base) }.call_on_size::<Frame>(width, height);
         
@@ -233,7 +238,11 @@ impl HasVisibilityInner for TestableFrame {
     }
 }
 
-impl MemberInner for TestableFrame {}
+impl MemberInner for TestableFrame {
+    fn on_accessibility_changed(&mut self, base: &mut MemberBase, name: Option<&str>, description: Option<&str>, role: a11y::Role) {
+        common::trace_accessibility(base, name, description, role);
+    }
+}
 
 impl Drawable for TestableFrame {
     fn draw(&mut self, _member: &mut MemberBase, control: &mut ControlBase) {
@@ -291,7 +300,7 @@ impl Drawable for TestableFrame {
         (control.measured.0, control.measured.1, control.measured != old_size)
     }
     fn invalidate(&mut self, _member: &mut MemberBase, _control: &mut ControlBase) {
-        self.base.invalidate();
+        self.base.relayout();
     }
 }
 
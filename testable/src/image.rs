@@ -16,6 +16,7 @@ impl HasImageInner for TestableImage {
     }
     fn set_image(&mut self, _: &mut MemberBase, arg0: Cow<image::DynamicImage>) {
         self.bmp = arg0.into_owned();
+        self.base.invalidate();
     }
 }
 impl<O: controls::Image> NewImageInner<O> for TestableImage {
@@ -65,6 +66,9 @@ impl ControlInner for TestableImage {
     fn on_removed_from_container(&mut self, _member: &mut MemberBase, _control: &mut ControlBase, _: &dyn controls::Container) {
 	    self.base.parent = None;
     }
+    fn on_style_changed(&mut self, _member: &mut MemberBase, _control: &mut ControlBase, style: &style::Style) {
+        self.base.on_style_changed(style);
+    }
 
     fn parent(&self) -> Option<&dyn controls::Member> {
         self.base.parent().map(|p| p.as_member())
@@ -80,17 +84,16 @@ impl ControlInner for TestableImage {
     }
 
     #[cfg(feature = "markup")]
-    fn fill_from_markup(&mut self, member: &mut MemberBase, _control: &mut ControlBase, markup: &plygui_api::markup::Markup, registry: &mut plygui_api::markup::MarkupRegistry) {
+    fn fill_from_markup(&mut self, member: &mut MemberBase, _control: &mut ControlBase, markup: &plygui_api::markup::Markup, registry: &mut plygui_api::markup::MarkupRegistry) -> Result<(), plygui_api::Error> {
         use plygui_api::markup::MEMBER_TYPE_IMAGE;
         fill_from_markup_base!(self, member, markup, registry, Image, [MEMBER_TYPE_IMAGE]);
         //TODO image source
+        Ok(())
     }
 }
 
 impl HasLayoutInner for TestableImage {
-    fn on_layout_changed(&mut self, _base: &mut MemberBase) {
-        self.base.invalidate();
-    }
+    fn on_layout_changed(&mut self, _base: &mut MemberBase) {}
 }
 
 impl HasNativeIdInner for TestableImage {
@@ -108,7 +111,6 @@ impl HasSizeInner for TestableImage {
         let this = base.as_any_mut().downcast_mut::<Image>().unwrap();
         this.set_layout_width(layout::Size::Exact(width));
         this.set_layout_width(layout::Size::Exact(height));
-        self.base.invalidate();
         
         unsafe { utils::base_to_impl_mut::<Image>(base) }.call_on_size::<Image>(width, height);
         
@@ -122,7 +124,11 @@ impl HasVisibilityInner for TestableImage {
     }
 }
 
-impl MemberInner for TestableImage {}
+impl MemberInner for TestableImage {
+    fn on_accessibility_changed(&mut self, base: &mut MemberBase, name: Option<&str>, description: Option<&str>, role: a11y::Role) {
+        common::trace_accessibility(base, name, description, role);
+    }
+}
 
 impl Drawable for TestableImage {
     fn draw(&mut self, _member: &mut MemberBase, control: &mut ControlBase) {
@@ -155,7 +161,7 @@ impl Drawable for TestableImage {
         (control.measured.0, control.measured.1, control.measured != old_size)
     }
     fn invalidate(&mut self, _member: &mut MemberBase, _control: &mut ControlBase) {
-        self.base.invalidate()
+        self.base.relayout()
     }
 }
 
@@ -140,19 +140,21 @@ impl ControlInner for TestableLinearLayout {
         }
         self.base.parent = None;
     }
+    fn on_style_changed(&mut self, _member: &mut MemberBase, _control: &mut ControlBase, style: &style::Style) {
+        self.base.on_style_changed(style);
+    }
 
     #[cfg(feature = "markup")]
-    fn fill_from_markup(&mut self, member: &mut MemberBase, _control: &mut ControlBase, markup: &plygui_api::markup::Markup, registry: &mut plygui_api::markup::MarkupRegistry) {
+    fn fill_from_markup(&mut self, member: &mut MemberBase, _control: &mut ControlBase, markup: &plygui_api::markup::Markup, registry: &mut plygui_api::markup::MarkupRegistry) -> Result<(), plygui_api::Error> {
         use plygui_api::markup::MEMBER_TYPE_LINEAR_LAYOUT;
 
         fill_from_markup_base!(self, member, markup, registry, LinearLayout, [MEMBER_TYPE_LINEAR_LAYOUT]);
         fill_from_markup_children!(self, member, markup, registry);
+        Ok(())
     }
 }
 impl HasLayoutInner for TestableLinearLayout {
-    fn on_layout_changed(&mut self, _base: &mut MemberBase) {
-        self.base.invalidate();
-    }
+    fn on_layout_changed(&mut self, _base: &mut MemberBase) {}
     fn layout_margin(&self, _member: &MemberBase) -> layout::BoundarySize {
         layout::BoundarySize::AllTheSame(DEFAULT_PADDING)
     }
@@ -164,7 +166,11 @@ impl HasNativeIdInner for TestableLinearLayout {
         self.base.id.into()
     }
 }
-impl MemberInner for TestableLinearLayout {}
+impl MemberInner for TestableLinearLayout {
+    fn on_accessibility_changed(&mut self, base: &mut MemberBase, name: Option<&str>, description: Option<&str>, role: a11y::Role) {
+        common::trace_accessibility(base, name, description, role);
+    }
+}
 
 impl HasSizeInner for TestableLinearLayout {
     fn on_size_set(&mut self, base: &mut MemberBase, (width, height): (u16, u16)) -> bool {
@@ -173,7 +179,6 @@ impl HasSizeInner for TestableLinearLayout {
         let this = base.as_any_mut().downcast_mut::<LinearLayout>().unwrap();
         this.set_layout_width(layout::Size::Exact(width));
         this.set_layout_width(layout::Size::Exact(height));
-        self.base.invalidate();
         
         unsafe { utils::base_to_impl_mut::<LinearLayout>(base) }.call_on_size::<LinearLayout>(width, height);
         
@@ -188,21 +193,10 @@ impl HasVisibilityInner for TestableLinearLayout {
 }
 
 impl ContainerInner for TestableLinearLayout {
-    fn find_control_mut<'a>(&'a mut self, arg: &'a types::FindBy) -> Option<&'a mut dyn controls::Control> {
+    fn find_control_mut<'a>(&'a mut self, arg: types::FindBy<'a>) -> Option<&'a mut dyn controls::Control> {
         for child in self.children.as_mut_slice() {
-            match arg {
-                types::FindBy::Id(ref id) => {
-                    if child.as_member_mut().id() == *id {
-                        return Some(child.as_mut());
-                    }
-                }
-                types::FindBy::Tag(ref tag) => {
-                    if let Some(mytag) = child.as_member_mut().tag() {
-                        if tag.as_str() == mytag {
-                            return Some(child.as_mut());
-                        }
-                    }
-                }
+            if arg.matches(child.as_member()) {
+                return Some(child.as_mut());
             }
             if let Some(c) = child.is_container_mut() {
                 let ret = c.find_control_mut(arg);
@@ -214,21 +208,10 @@ impl ContainerInner for TestableLinearLayout {
         }
         None
     }
-    fn find_control<'a>(&'a self, arg: &'a types::FindBy) -> Option<&'a dyn controls::Control> {
+    fn find_control<'a>(&'a self, arg: types::FindBy<'a>) -> Option<&'a dyn controls::Control> {
         for child in self.children.as_slice() {
-            match arg {
-                types::FindBy::Id(ref id) => {
-                    if child.as_member().id() == *id {
-                        return Some(child.as_ref());
-                    }
-                }
-                types::FindBy::Tag(ref tag) => {
-                    if let Some(mytag) = child.as_member().tag() {
-                        if tag.as_str() == mytag {
-                            return Some(child.as_ref());
-                        }
-                    }
-                }
+            if arg.matches(child.as_member()) {
+                return Some(child.as_ref());
             }
             if let Some(c) = child.is_container() {
                 let ret = c.find_control(arg);
@@ -243,15 +226,21 @@ impl ContainerInner for TestableLinearLayout {
 }
 
 impl Drawable for TestableLinearLayout {
-    fn draw(&mut self, _member: &mut MemberBase, control: &mut ControlBase) {
+    fn draw(&mut self, member: &mut MemberBase, control: &mut ControlBase) {
         self.base.draw("LinearLayout", control.coords, control.measured);
-        let mut x = DEFAULT_PADDING;
+        // horizontal children flow from the right edge under RTL
+        let rtl = self.orientation == layout::Orientation::Horizontal && member.as_member().layout_direction().is_rtl();
+        let mut x = if rtl { control.measured.0 as i32 - DEFAULT_PADDING } else { DEFAULT_PADDING };
         let mut y = DEFAULT_PADDING;
         for ref mut child in self.children.as_mut_slice() {
-            child.draw(Some((x, y)));
             let (xx, yy) = child.size();
+            if rtl {
+                x -= xx as i32;
+            }
+            child.draw(Some((x, y)));
             match self.orientation {
-                layout::Orientation::Horizontal => x += xx as i32,
+                layout::Orientation::Horizontal if !rtl => x += xx as i32,
+                layout::Orientation::Horizontal => {}
                 layout::Orientation::Vertical => y += yy as i32,
             }
         }
@@ -317,6 +306,42 @@ impl Drawable for TestableLinearLayout {
         (control.measured.0, control.measured.1, control.measured != old_size)
     }
     fn invalidate(&mut self, _member: &mut MemberBase, _control: &mut ControlBase) {
-        self.base.invalidate()
+        self.base.relayout()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+    use plygui_api::sdk::OuterDrawable;
+
+    fn child_xs(layout: &dyn plygui_api::controls::LinearLayout) -> Vec<i32> {
+        (0..layout.len()).map(|i| layout.child_at(i).unwrap().coords().unwrap().0).collect()
+    }
+
+    #[test]
+    fn horizontal_children_are_mirrored_under_rtl() {
+        let _app = imp::Application::with_name("rtl");
+        let mut layout = imp::LinearLayout::with_orientation(layout::Orientation::Horizontal);
+        layout.set_layout_width(layout::Size::Exact(200));
+        let mut widths = Vec::new();
+        for label in ["ok", "a longer label"].iter() {
+            let mut button = imp::Button::with_label(label);
+            button.set_layout_width(layout::Size::WrapContent);
+            layout.push_child(button.into_control());
+        }
+        let layout = layout.as_any_mut().downcast_mut::<super::LinearLayout>().unwrap();
+        layout.measure(200, 100);
+        layout.draw(Some((0, 0)));
+        for i in 0..layout.len() {
+            widths.push(layout.child_at(i).unwrap().size().0 as i32);
+        }
+        let padding = common::DEFAULT_PADDING;
+        assert_eq!(child_xs(layout), vec![padding, padding + widths[0]]);
+
+        layout.set_layout_direction(layout::Direction::RightToLeft);
+        layout.measure(200, 100);
+        layout.draw(Some((0, 0)));
+        assert_eq!(child_xs(layout), vec![200 - padding - widths[0], 200 - padding - widths[0] - widths[1]]);
     }
 }
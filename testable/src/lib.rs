@@ -8,18 +8,25 @@ pub mod common;
 
 mod application;
 mod button;
+mod clipboard;
 mod frame;
 mod image;
 mod layout_linear;
 mod message;
+mod file_dialog;
 mod splitted;
 mod text;
 mod tray;
 mod window;
 mod progress_bar;
 mod list;
+mod tree;
+mod table;
 
 mod better_button; 
 
 default_markup_register_members!();
 default_pub_use!();
+
+pub use crate::file_dialog::TestableFileDialog;
+pub use crate::message::{TestableMessage, TestableMessageAnswer, TestableShownMessage};
@@ -6,20 +6,20 @@ pub type List = AMember<AControl<AContainer<AAdapted<AList<TestableList>>>>>;
 pub struct TestableList {
     base: TestableControlBase<List>,
     items: Vec<Box<dyn controls::Control>>,
-    on_item_click: Option<callbacks::OnItemClick>,
+    on_item_click: callbacks::CallbackList<callbacks::OnItemClick>,
 }
 impl<O: controls::List> NewListInner<O> for TestableList {
     fn with_uninit(u: &mut mem::MaybeUninit<O>) -> Self {
         TestableList {
             base: common::TestableControlBase::with_id(u),
             items: vec![],
-            on_item_click: None,
+            on_item_click: callbacks::CallbackList::new(),
         }
     }
 }
 impl ListInner for TestableList {
 	fn with_adapter(adapter: Box<dyn types::Adapter>) -> Box<dyn controls::List> {
-        let len = adapter.len();
+        let len = adapter.len_at(&[]).unwrap_or(0);
         let mut b: Box<mem::MaybeUninit<List>> = Box::new_uninit();
         let mut ab = AMember::with_inner(
             AControl::with_inner(
@@ -42,29 +42,39 @@ impl ListInner for TestableList {
     }
 }
 impl ItemClickableInner for TestableList {
-    fn item_click(&mut self, i: usize, item_view: &mut dyn controls::Control, skip_callbacks: bool) {
+    fn item_click(&mut self, i: &[usize], item_view: &mut dyn controls::Control, skip_callbacks: bool) {
         if !skip_callbacks{
+            let id = self.base.id;
             let self2 = self.base.as_outer_mut();
-            if let Some(ref mut callback) = self.on_item_click {
+            let mut callbacks = self.on_item_click.take();
+            for callback in callbacks.iter_mut() {
+                common::trace_callback(id, callback);
                 (callback.as_mut())(self2, i, item_view)
             }
+            self.on_item_click.restore(callbacks);
         }
     }
     fn on_item_click(&mut self, callback: Option<callbacks::OnItemClick>) {
-        self.on_item_click = callback;
+        self.on_item_click.set(callback);
+    }
+    fn add_on_item_click(&mut self, callback: callbacks::OnItemClick) -> callbacks::CallbackId {
+        self.on_item_click.add(callback)
+    }
+    fn remove_on_item_click(&mut self, id: callbacks::CallbackId) -> bool {
+        self.on_item_click.remove(id)
     }
 }
 impl AdaptedInner for TestableList {
-    fn on_item_change<'a>(&mut self, _: &mut MemberBase, value: adapter::Change<'a>) {
+    fn on_item_change<'a>(&mut self, base: &mut MemberBase, value: adapter::Change<'a>) {
         match value {
             adapter::Change::Added(index,_) => {
-                println!("item added {}", index[0]);
+                plygui_api::trace::backend(base, format_args!("item added {}", index[0]));
             },
             adapter::Change::Removed(index) => {
-                println!("item removed {}", index[0]);
+                plygui_api::trace::backend(base, format_args!("item removed {}", index[0]));
             },
             adapter::Change::Edited(index,_) => {
-                println!("item edited {}", index[0]);
+                plygui_api::trace::backend(base, format_args!("item edited {}", index[0]));
             }
         }
     }
@@ -94,13 +104,14 @@ impl ControlInner for TestableList {
         let (member, _, adapter, _) = List::as_adapted_parts_mut(unsafe { utils::base_to_impl_mut(member) });
 
         let mut y = 0;
-        for i in 0..adapter.adapter.len() {
+        for i in 0..adapter.adapter.len_at(&[]).unwrap_or(0) {
             let self2: &mut List = unsafe { utils::base_to_impl_mut(member) };
-            let mut item = adapter.adapter.spawn_item_view(&[i], adapter::Node::Leaf, self2);
-            item.on_added_to_container(self2, 0, y, utils::coord_to_size(pw as i32) as u16, utils::coord_to_size(ph as i32) as u16);
-            let (_, yy) = item.size();
-            self.items.push(item);
-            y += yy as i32;
+            if let Some(mut item) = adapter.adapter.spawn_item_view(&[i], self2) {
+                item.on_added_to_container(self2, 0, y, utils::coord_to_size(pw as i32) as u16, utils::coord_to_size(ph as i32) as u16);
+                let (_, yy) = item.size();
+                self.items.push(item);
+                y += yy as i32;
+            }
         }
     }
     fn on_removed_from_container(&mut self, member: &mut MemberBase, _control: &mut ControlBase, _: &dyn controls::Container) {
@@ -110,31 +121,24 @@ impl ControlInner for TestableList {
         }
         self.base.parent = None;
     }
+    fn on_style_changed(&mut self, _member: &mut MemberBase, _control: &mut ControlBase, style: &style::Style) {
+        self.base.on_style_changed(style);
+    }
 
     #[cfg(feature = "markup")]
-    fn fill_from_markup(&mut self, member: &mut MemberBase, _control: &mut ControlBase, markup: &plygui_api::markup::Markup, registry: &mut plygui_api::markup::MarkupRegistry) {
+    fn fill_from_markup(&mut self, member: &mut MemberBase, _control: &mut ControlBase, markup: &plygui_api::markup::Markup, registry: &mut plygui_api::markup::MarkupRegistry) -> Result<(), plygui_api::Error> {
         use plygui_api::markup::MEMBER_TYPE_LIST;
 
         fill_from_markup_base!(self, member, markup, registry, List, [MEMBER_TYPE_LIST]);
         //fill_from_markup_items!(self, member, markup, registry);
+        Ok(())
     }
 }
 impl ContainerInner for TestableList {
-    fn find_control_mut<'a>(&'a mut self, arg: &'a types::FindBy) -> Option<&'a mut dyn controls::Control> {
+    fn find_control_mut<'a>(&'a mut self, arg: types::FindBy<'a>) -> Option<&'a mut dyn controls::Control> {
         for item in self.items.as_mut_slice() {
-            match arg {
-                types::FindBy::Id(ref id) => {
-                    if item.as_member_mut().id() == *id {
-                        return Some(item.as_mut());
-                    }
-                }
-                types::FindBy::Tag(ref tag) => {
-                    if let Some(mytag) = item.as_member_mut().tag() {
-                        if tag.as_str() == mytag {
-                            return Some(item.as_mut());
-                        }
-                    }
-                }
+            if arg.matches(item.as_member()) {
+                return Some(item.as_mut());
             }
             if let Some(c) = item.is_container_mut() {
                 let ret = c.find_control_mut(arg);
@@ -146,21 +150,10 @@ impl ContainerInner for TestableList {
         }
         None
     }
-    fn find_control<'a>(&'a self, arg: &'a types::FindBy) -> Option<&'a dyn controls::Control> {
+    fn find_control<'a>(&'a self, arg: types::FindBy<'a>) -> Option<&'a dyn controls::Control> {
         for item in self.items.as_slice() {
-            match arg {
-                types::FindBy::Id(ref id) => {
-                    if item.as_member().id() == *id {
-                        return Some(item.as_ref());
-                    }
-                }
-                types::FindBy::Tag(ref tag) => {
-                    if let Some(mytag) = item.as_member().tag() {
-                        if tag.as_str() == mytag {
-                            return Some(item.as_ref());
-                        }
-                    }
-                }
+            if arg.matches(item.as_member()) {
+                return Some(item.as_ref());
             }
             if let Some(c) = item.is_container() {
                 let ret = c.find_control(arg);
@@ -174,9 +167,7 @@ impl ContainerInner for TestableList {
     }
 }
 impl HasLayoutInner for TestableList {
-    fn on_layout_changed(&mut self, _base: &mut MemberBase) {
-        self.base.invalidate();
-    }
+    fn on_layout_changed(&mut self, _base: &mut MemberBase) {}
 }
 impl HasNativeIdInner for TestableList {
     type Id = common::TestableId;
@@ -185,7 +176,11 @@ impl HasNativeIdInner for TestableList {
         self.base.id.into()
     }
 }
-impl MemberInner for TestableList {}
+impl MemberInner for TestableList {
+    fn on_accessibility_changed(&mut self, base: &mut MemberBase, name: Option<&str>, description: Option<&str>, role: a11y::Role) {
+        common::trace_accessibility(base, name, description, role);
+    }
+}
 
 impl HasSizeInner for TestableList {
     fn on_size_set(&mut self, base: &mut MemberBase, (width, height): (u16, u16)) -> bool {
@@ -194,7 +189,6 @@ impl HasSizeInner for TestableList {
         let this = base.as_any_mut().downcast_mut::<List>().unwrap();
         this.set_layout_width(layout::Size::Exact(width));
         this.set_layout_width(layout::Size::Exact(height));
-        self.base.invalidate();
         true
     }
 }
@@ -230,6 +224,6 @@ impl Drawable for TestableList {
         (control.measured.0, control.measured.1, control.measured != old_size)
     }
     fn invalidate(&mut self, _member: &mut MemberBase, _control: &mut ControlBase) {
-        self.base.invalidate()
+        self.base.relayout()
     }
 }
@@ -1,21 +1,44 @@
 use crate::common::{self, *};
 
+use std::cell::RefCell;
+use std::collections::VecDeque;
+
+/// Scripted reply to the next started message.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum TestableMessageAnswer {
+    Title(String),
+    Index(usize),
+    /// Same as `Index`, but with an explicit value for the requested input.
+    Input(usize, types::MessageInputValue),
+    Cancel,
+}
+
+/// A message that has been started, as seen by the user.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TestableShownMessage {
+    pub label: String,
+    pub text: String,
+    pub severity: types::MessageSeverity,
+}
+
+#[derive(Default)]
+struct TestableMessageScript {
+    answers: VecDeque<TestableMessageAnswer>,
+    shown: Vec<TestableShownMessage>,
+    strict: bool,
+}
+
+thread_local! {
+    static SCRIPT: RefCell<TestableMessageScript> = const { RefCell::new(TestableMessageScript { answers: VecDeque::new(), shown: Vec::new(), strict: false }) };
+}
+
 struct TestableMessageAction {
     title: String,
-    id: i32,
     cb: callbacks::Action,
 }
 impl From<(String, callbacks::Action)> for TestableMessageAction {
     fn from(a: (String, callbacks::Action)) -> Self {
         TestableMessageAction {
-            id: {
-                use std::collections::hash_map::DefaultHasher;
-                use std::hash::{Hash, Hasher};
-
-                let mut hasher = DefaultHasher::new();
-                a.0.hash(&mut hasher);
-                hasher.finish() as i32
-            },
             title: a.0,
             cb: a.1,
         }
@@ -29,13 +52,38 @@ pub struct TestableMessage {
     label: String,
     text: String,
     severity: types::MessageSeverity,
+    input: types::MessageInput,
     actions: Vec<TestableMessageAction>,
 }
 
 pub type Message = AMember<AMessage<TestableMessage>>;
 
+impl TestableMessage {
+    /// Queues the answer for the next started message. Unanswered messages pick the first action,
+    /// the ones without actions are closed as if by the button the backend adds to them.
+    pub fn push_answer(answer: TestableMessageAnswer) {
+        SCRIPT.with(|s| s.borrow_mut().answers.push_back(answer));
+    }
+    pub fn clear_answers() {
+        SCRIPT.with(|s| s.borrow_mut().answers.clear());
+    }
+    /// In strict mode, a message started with no queued answer panics.
+    pub fn set_strict(strict: bool) {
+        SCRIPT.with(|s| s.borrow_mut().strict = strict);
+    }
+    pub fn shown() -> Vec<TestableShownMessage> {
+        SCRIPT.with(|s| s.borrow().shown.clone())
+    }
+    pub fn clear_shown() {
+        SCRIPT.with(|s| s.borrow_mut().shown.clear());
+    }
+    fn no_action(&self, action: std::fmt::Arguments) -> ! {
+        panic!("Message '{}' has no action {}", self.label, action)
+    }
+}
+
 impl HasLabelInner for TestableMessage {
-    fn label(&self, _base: &MemberBase) -> Cow<str> {
+    fn label(&self, _base: &MemberBase) -> Cow<'_, str> {
         Cow::Borrowed(self.label.as_ref())
     }
     fn set_label(&mut self, _base: &mut MemberBase, label: Cow<str>) {
@@ -44,7 +92,7 @@ impl HasLabelInner for TestableMessage {
 }
 
 impl MessageInner for TestableMessage {
-    fn with_actions(content: types::TextContent, severity: types::MessageSeverity, actions: Vec<(String, callbacks::Action)>, parent: Option<&dyn controls::Member>) -> Box<dyn controls::Message> {
+    fn with_input(content: types::TextContent, severity: types::MessageSeverity, input: types::MessageInput, actions: Vec<(String, callbacks::Action)>, parent: Option<&dyn controls::Member>) -> Box<dyn controls::Message> {
         let (label, text) = match content {
             types::TextContent::Plain(text) => (String::new(/* TODO app name here? */), text),
             types::TextContent::LabelDescription(label, description) => (label, description),
@@ -54,21 +102,76 @@ impl MessageInner for TestableMessage {
                 TestableMessage {
                     id: ptr::null_mut(),
                     parent: parent.map(|p|p.id()),
-                    label: label,
-                    text: text,
-                    severity: severity,
+                    label,
+                    text,
+                    severity,
+                    input,
                     actions: actions.into_iter().map(|a| a.into()).collect(),
                 }
             )
         ));
         a
     }
-    fn start(self) -> Result<String, ()> {
-        self.actions.iter().nth(0)/*find(|a| a.id == pressed)*/.map(|a| a.title.clone()).ok_or(())
+    /// Calls the callback of the chosen action with the parent member, if the message has one.
+    fn start(mut self) -> Result<types::MessageResponse, ()> {
+        let answer = SCRIPT.with(|s| {
+            let mut s = s.borrow_mut();
+            s.shown.push(TestableShownMessage {
+                label: self.label.clone(),
+                text: self.text.clone(),
+                severity: self.severity,
+            });
+            match s.answers.pop_front() {
+                Some(answer) => answer,
+                None if s.strict => panic!("Unexpected message '{}': {}", self.label, self.text),
+                None => TestableMessageAnswer::Index(0),
+            }
+        });
+        let (index, input) = match answer {
+            TestableMessageAnswer::Cancel => return Err(()),
+            TestableMessageAnswer::Index(index) => (index, default_input_value(&self.input)),
+            TestableMessageAnswer::Input(index, input) => (index, input),
+            TestableMessageAnswer::Title(title) => match self.actions.iter().position(|a| a.title == title) {
+                Some(index) => (index, default_input_value(&self.input)),
+                None => self.no_action(format_args!("'{}'", title)),
+            },
+        };
+        if self.actions.is_empty() && index == 0 {
+            return Ok(types::MessageResponse {
+                action: 0,
+                title: String::new(),
+                input,
+            });
+        }
+        if index >= self.actions.len() {
+            self.no_action(format_args!("#{}", index));
+        }
+        let parent = self.parent.and_then(|id| utils::application_mut()?.find_member_mut(types::FindBy::Id(id)));
+        let action = &mut self.actions[index];
+        if let Some(parent) = parent {
+            (action.cb.as_mut())(parent);
+        }
+        Ok(types::MessageResponse {
+            action: index,
+            title: action.title.clone(),
+            input,
+        })
     }
     fn severity(&self) -> types::MessageSeverity {
         self.severity
     }
+    fn input(&self) -> &types::MessageInput {
+        &self.input
+    }
+}
+
+fn default_input_value(input: &types::MessageInput) -> types::MessageInputValue {
+    match input {
+        types::MessageInput::None => types::MessageInputValue::None,
+        types::MessageInput::Text(default) => types::MessageInputValue::Text(default.clone()),
+        types::MessageInput::Choice(_, default) => types::MessageInputValue::Choice(*default),
+        types::MessageInput::Confirm(_, checked) => types::MessageInputValue::Confirm(*checked),
+    }
 }
 
 impl HasNativeIdInner for TestableMessage {
@@ -79,5 +182,89 @@ impl HasNativeIdInner for TestableMessage {
     }
 }
 
-impl MemberInner for TestableMessage {}
+impl MemberInner for TestableMessage {
+    fn on_accessibility_changed(&mut self, base: &mut MemberBase, name: Option<&str>, description: Option<&str>, role: a11y::Role) {
+        common::trace_accessibility(base, name, description, role);
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+    use plygui_api::ids;
+    use plygui_api::types::{MessageInputValue, MessageResponse, MessageSeverity, TextContent};
+    use std::{cell::RefCell, rc::Rc};
+
+    fn ask(actions: &[&str]) -> Box<dyn Message> {
+        let actions = actions.iter().map(|title| (title.to_string(), (|_: &mut dyn Member| true).into())).collect();
+        imp::Message::with_actions(TextContent::LabelDescription("Save".into(), "Save the changes?".into()), MessageSeverity::Question, actions, None)
+    }
+
+    #[test]
+    fn start_returns_the_answered_action() {
+        TestableMessage::clear_answers();
+        TestableMessage::push_answer(TestableMessageAnswer::Title("No".into()));
+        TestableMessage::push_answer(TestableMessageAnswer::Index(0));
+        assert_eq!(ask(&["Yes", "No"]).start(), Ok(MessageResponse { action: 1, title: "No".into(), input: MessageInputValue::None }));
+        assert_eq!(ask(&["Yes", "No"]).start(), Ok(MessageResponse { action: 0, title: "Yes".into(), input: MessageInputValue::None }));
+        assert_eq!(ask(&["Yes", "No"]).start().unwrap().action, 0);
+        TestableMessage::push_answer(TestableMessageAnswer::Input(0, MessageInputValue::Text("draft".into())));
+        let response = imp::Message::ask_text(TextContent::Plain("Name?".into()), "", vec![("Ok".into(), (|_: &mut dyn Member| true).into())], None);
+        assert_eq!(response.unwrap().input, MessageInputValue::Text("draft".into()));
+    }
+
+    #[test]
+    fn start_reports_a_cancel() {
+        TestableMessage::clear_answers();
+        TestableMessage::push_answer(TestableMessageAnswer::Cancel);
+        assert_eq!(ask(&["Yes", "No"]).start(), Err(()));
+    }
+
+    #[test]
+    fn messages_without_actions_are_closed() {
+        TestableMessage::clear_answers();
+        let info = imp::Message::with_content(TextContent::Plain("Saved".into()), MessageSeverity::Info, None);
+        assert_eq!(info.start(), Ok(MessageResponse { action: 0, title: String::new(), input: MessageInputValue::None }));
+    }
+
+    #[test]
+    #[should_panic(expected = "Message 'Save' has no action #2")]
+    fn answering_a_missing_index_panics() {
+        TestableMessage::push_answer(TestableMessageAnswer::Index(2));
+        let _ = ask(&["Yes", "No"]).start();
+    }
+
+    #[test]
+    #[should_panic(expected = "Message 'Save' has no action 'Maybe'")]
+    fn answering_a_missing_title_panics() {
+        TestableMessage::push_answer(TestableMessageAnswer::Title("Maybe".into()));
+        let _ = ask(&["Yes", "No"]).start();
+    }
+
+
+    #[test]
+    fn start_calls_the_chosen_action_with_the_parent() {
+        let mut app = imp::Application::with_name("message actions");
+        let wid = app.new_window::<imp::Window>("w", WindowStartSize::Exact(200, 100), Menu::None);
+        let pressed = Rc::new(RefCell::new(Vec::new()));
+        let actions = |pressed: &Rc<RefCell<Vec<(String, ids::Id)>>>| {
+            ["Yes", "No"]
+                .iter()
+                .map(|title| {
+                    let (pressed, title) = (pressed.clone(), title.to_string());
+                    (title.clone(), (move |parent: &mut dyn Member| { pressed.borrow_mut().push((title.clone(), parent.id())); true }).into())
+                })
+                .collect()
+        };
+        let content = || TextContent::Plain("Quit?".into());
+        TestableMessage::push_answer(TestableMessageAnswer::Title("No".into()));
+        let message = imp::Message::with_actions(content(), MessageSeverity::Question, actions(&pressed), app.find_member(FindBy::Id(wid)));
+        assert_eq!(message.start().unwrap().action, 1);
+        assert_eq!(*pressed.borrow(), vec![("No".to_string(), wid)]);
 
+        pressed.borrow_mut().clear();
+        assert_eq!(imp::Message::start_with_actions(content(), MessageSeverity::Question, actions(&pressed), None).unwrap().action, 0);
+        assert!(pressed.borrow().is_empty());
+    }
+}
@@ -28,18 +28,19 @@ impl<O: controls::ProgressBar> NewProgressBarInner<O> for TestableProgressBar {
 impl ProgressBarInner for TestableProgressBar {
     fn with_progress(arg: types::Progress) -> Box<dyn controls::ProgressBar> {
         let mut b: Box<mem::MaybeUninit<ProgressBar>> = Box::new_uninit();
-        let mut ab = AMember::with_inner(
+        let ab = AMember::with_inner(
             AControl::with_inner(
                 AProgressBar::with_inner(
                     <Self as NewProgressBarInner<ProgressBar>>::with_uninit(b.as_mut()),
                 ),
             )
         );
-        controls::HasProgress::set_progress(&mut ab, arg);
-        unsafe {
+        let mut b = unsafe {
 	        b.as_mut_ptr().write(ab);
 	        b.assume_init()
-        }
+        };
+        controls::HasProgress::set_progress(b.as_mut(), arg);
+        b
     }
 }
 impl Spawnable for TestableProgressBar {
@@ -55,6 +56,9 @@ impl ControlInner for TestableProgressBar {
     fn on_removed_from_container(&mut self, _member: &mut MemberBase, _control: &mut ControlBase, _: &dyn controls::Container) {
         self.base.parent = None;
     }
+    fn on_style_changed(&mut self, _member: &mut MemberBase, _control: &mut ControlBase, style: &style::Style) {
+        self.base.on_style_changed(style);
+    }
     fn parent(&self) -> Option<&dyn controls::Member> {
         self.base.parent().map(|p| p.as_member())
     }
@@ -69,16 +73,15 @@ impl ControlInner for TestableProgressBar {
     }
 
     #[cfg(feature = "markup")]
-    fn fill_from_markup(&mut self, member: &mut MemberBase, _control: &mut ControlBase, markup: &plygui_api::markup::Markup, registry: &mut plygui_api::markup::MarkupRegistry) {
+    fn fill_from_markup(&mut self, member: &mut MemberBase, _control: &mut ControlBase, markup: &plygui_api::markup::Markup, registry: &mut plygui_api::markup::MarkupRegistry) -> Result<(), plygui_api::Error> {
         use plygui_api::markup::MEMBER_TYPE_PROGRESS_BAR;
         fill_from_markup_base!(self, member, markup, registry, ProgressBar, [MEMBER_TYPE_PROGRESS_BAR]);
+        Ok(())
     }
 }
 
 impl HasLayoutInner for TestableProgressBar {
-    fn on_layout_changed(&mut self, _base: &mut MemberBase) {
-        self.base.invalidate();
-    }
+    fn on_layout_changed(&mut self, _base: &mut MemberBase) {}
 }
 
 impl HasNativeIdInner for TestableProgressBar {
@@ -96,7 +99,6 @@ impl HasSizeInner for TestableProgressBar {
         let this = base.as_any_mut().downcast_mut::<ProgressBar>().unwrap();
         this.set_layout_width(layout::Size::Exact(width));
         this.set_layout_width(layout::Size::Exact(height));
-        self.base.invalidate();
 
         unsafe { utils::base_to_impl_mut::<ProgressBar>(base) }.call_on_size::<ProgressBar>(width, height);
 
@@ -110,7 +112,11 @@ impl HasVisibilityInner for TestableProgressBar {
     }
 }
 
-impl MemberInner for TestableProgressBar {}
+impl MemberInner for TestableProgressBar {
+    fn on_accessibility_changed(&mut self, base: &mut MemberBase, name: Option<&str>, description: Option<&str>, role: a11y::Role) {
+        common::trace_accessibility(base, name, description, role);
+    }
+}
 
 impl Drawable for TestableProgressBar {
     fn draw(&mut self, _member: &mut MemberBase, control: &mut ControlBase) {
@@ -138,6 +144,6 @@ impl Drawable for TestableProgressBar {
         (control.measured.0, control.measured.1, control.measured != old_size)
     }
     fn invalidate(&mut self, _member: &mut MemberBase, _control: &mut ControlBase) {
-        self.base.invalidate()
+        self.base.relayout()
     }
 }
@@ -79,7 +79,7 @@ impl<O: controls::Splitted> NewSplittedInner<O> for TestableSplitted {
 impl SplittedInner for TestableSplitted {
     fn with_content(first: Box<dyn controls::Control>, second: Box<dyn controls::Control>, orientation: layout::Orientation) -> Box<dyn controls::Splitted> {
         let mut b: Box<mem::MaybeUninit<Splitted>> = Box::new_uninit();
-        let mut ab = AMember::with_inner(
+        let ab = AMember::with_inner(
             AControl::with_inner(
                 AContainer::with_inner(
                     AMultiContainer::with_inner(
@@ -90,11 +90,12 @@ impl SplittedInner for TestableSplitted {
                 ),
             )
         );
-        controls::HasOrientation::set_orientation(&mut ab, orientation);
-        unsafe {
+        let mut b = unsafe {
             b.as_mut_ptr().write(ab);
 	        b.assume_init()
-        }
+        };
+        controls::HasOrientation::set_orientation(b.as_mut(), orientation);
+        b
     }
     fn set_splitter(&mut self, _member: &mut MemberBase, pos: f32) {
         self.splitter = pos;
@@ -136,7 +137,6 @@ impl HasSizeInner for TestableSplitted {
         let this = base.as_any_mut().downcast_mut::<Splitted>().unwrap();
         this.set_layout_width(layout::Size::Exact(width));
         this.set_layout_width(layout::Size::Exact(height));
-        self.base.invalidate();
         
         unsafe { utils::base_to_impl_mut::<Splitted>(base) }.call_on_size::<Splitted>(width, height);
         
@@ -149,7 +149,11 @@ impl HasVisibilityInner for TestableSplitted {
     }
 }
 
-impl MemberInner for TestableSplitted {}
+impl MemberInner for TestableSplitted {
+    fn on_accessibility_changed(&mut self, base: &mut MemberBase, name: Option<&str>, description: Option<&str>, role: a11y::Role) {
+        common::trace_accessibility(base, name, description, role);
+    }
+}
 
 impl ControlInner for TestableSplitted {
     fn parent(&self) -> Option<&dyn controls::Member> {
@@ -195,20 +199,23 @@ impl ControlInner for TestableSplitted {
         
         self.base.parent = None;
     }
+    fn on_style_changed(&mut self, _member: &mut MemberBase, _control: &mut ControlBase, style: &style::Style) {
+        self.base.on_style_changed(style);
+    }
 
     #[cfg(feature = "markup")]
-    fn fill_from_markup(&mut self, member: &mut MemberBase, _control: &mut ControlBase, markup: &plygui_api::markup::Markup, registry: &mut plygui_api::markup::MarkupRegistry) {
+    fn fill_from_markup(&mut self, member: &mut MemberBase, _control: &mut ControlBase, markup: &plygui_api::markup::Markup, registry: &mut plygui_api::markup::MarkupRegistry) -> Result<(), plygui_api::Error> {
         use plygui_api::markup::MEMBER_TYPE_SPLITTED;
 
         fill_from_markup_base!(self, member, markup, registry, Splitted, [MEMBER_TYPE_SPLITTED]);
         fill_from_markup_children!(self, member, markup, registry);
+        Ok(())
     }
 }
 
 impl HasLayoutInner for TestableSplitted {
     fn on_layout_changed(&mut self, _base: &mut MemberBase) {
         //self.update_children_layout();
-        self.base.invalidate();
     }
     fn layout_margin(&self, _member: &MemberBase) -> layout::BoundarySize {
         layout::BoundarySize::AllTheSame(DEFAULT_PADDING)
@@ -216,28 +223,12 @@ impl HasLayoutInner for TestableSplitted {
 }
 
 impl ContainerInner for TestableSplitted {
-    fn find_control_mut<'a>(&'a mut self, arg: &'a types::FindBy) -> Option<&'a mut dyn controls::Control> {
-        match arg {
-            types::FindBy::Id(id) => {
-                if self.first().as_member().id() == *id {
-                    return Some(self.first_mut());
-                }
-                if self.second().as_member().id() == *id {
-                    return Some(self.second_mut());
-                }
-            }
-            types::FindBy::Tag(ref tag) => {
-                if let Some(mytag) = self.first.as_member().tag() {
-                    if tag.as_str() == mytag {
-                        return Some(self.first_mut());
-                    }
-                }
-                if let Some(mytag) = self.second.as_member().tag() {
-                    if tag.as_str() == mytag {
-                        return Some(self.second_mut());
-                    }
-                }
-            }
+    fn find_control_mut<'a>(&'a mut self, arg: types::FindBy<'a>) -> Option<&'a mut dyn controls::Control> {
+        if arg.matches(self.first().as_member()) {
+            return Some(self.first_mut());
+        }
+        if arg.matches(self.second().as_member()) {
+            return Some(self.second_mut());
         }
 
         let self2: &mut TestableSplitted = unsafe { mem::transmute(self as *mut TestableSplitted) }; // bck is stupid
@@ -255,28 +246,12 @@ impl ContainerInner for TestableSplitted {
         }
         None
     }
-    fn find_control<'a>(&'a self, arg: &'a types::FindBy) -> Option<&'a dyn controls::Control> {
-        match arg {
-            types::FindBy::Id(id) => {
-                if self.first().as_member().id() == *id {
-                    return Some(self.first());
-                }
-                if self.second().as_member().id() == *id {
-                    return Some(self.second());
-                }
-            }
-            types::FindBy::Tag(ref tag) => {
-                if let Some(mytag) = self.first.as_member().tag() {
-                    if tag.as_str() == mytag {
-                        return Some(self.first.as_ref());
-                    }
-                }
-                if let Some(mytag) = self.second.as_member().tag() {
-                    if tag.as_str() == mytag {
-                        return Some(self.second.as_ref());
-                    }
-                }
-            }
+    fn find_control<'a>(&'a self, arg: types::FindBy<'a>) -> Option<&'a dyn controls::Control> {
+        if arg.matches(self.first().as_member()) {
+            return Some(self.first());
+        }
+        if arg.matches(self.second().as_member()) {
+            return Some(self.second());
         }
         if let Some(c) = self.first().is_container() {
             let ret = c.find_control(arg);
@@ -441,6 +416,6 @@ impl Drawable for TestableSplitted {
         (control.measured.0, control.measured.1, control.measured != old_size)
     }
     fn invalidate(&mut self, _member: &mut MemberBase, _control: &mut ControlBase) {
-        self.base.invalidate()
+        self.base.relayout()
     }
 }
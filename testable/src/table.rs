@@ -0,0 +1,268 @@
+use crate::common::{self, *};
+
+pub type Table = AMember<AControl<AContainer<AAdapted<ATable<TestableTable>>>>>;
+
+#[repr(C)]
+pub struct TestableTable {
+    base: TestableControlBase<Table>,
+    /// Cell views keyed by their adapter index.
+    cells: Vec<(Vec<usize>, Box<dyn controls::Control>)>,
+    headers_visible: bool,
+    column_widths: Vec<layout::Size>,
+    row_heights: Vec<layout::Size>,
+    on_item_click: callbacks::CallbackList<callbacks::OnItemClick>,
+}
+impl<O: controls::Table> NewTableInner<O> for TestableTable {
+    fn with_uninit_params(u: &mut mem::MaybeUninit<O>, width: usize, height: usize) -> Self {
+        TestableTable {
+            base: common::TestableControlBase::with_id(u),
+            cells: Vec::with_capacity(width * height),
+            headers_visible: true,
+            column_widths: vec![layout::Size::WrapContent; width],
+            row_heights: vec![layout::Size::WrapContent; height],
+            on_item_click: callbacks::CallbackList::new(),
+        }
+    }
+}
+impl TableInner for TestableTable {
+    fn with_adapter_initial_size(adapter: Box<dyn types::Adapter>, width: usize, height: usize) -> Box<dyn controls::Table> {
+        let mut b: Box<mem::MaybeUninit<Table>> = Box::new_uninit();
+        let ab = AMember::with_inner(
+            AControl::with_inner(
+                AContainer::with_inner(
+                    AAdapted::with_inner(
+                        ATable::with_inner(
+                            <Self as NewTableInner<Table>>::with_uninit_params(b.as_mut(), width, height)
+                        ),
+                        adapter,
+                        &mut b,
+                    ),
+                )
+            )
+        );
+        unsafe {
+            b.as_mut_ptr().write(ab);
+            b.assume_init()
+        }
+    }
+    fn set_headers_visible(&mut self, _member: &mut MemberBase, _control: &mut ControlBase, _adapted: &mut AdaptedBase, visible: bool) {
+        if self.headers_visible != visible {
+            self.headers_visible = visible;
+            self.base.invalidate();
+        }
+    }
+    fn headers_visible(&self, _member: &MemberBase, _control: &ControlBase, _adapted: &AdaptedBase) -> bool {
+        self.headers_visible
+    }
+    fn set_column_width(&mut self, _member: &mut MemberBase, _control: &mut ControlBase, _adapted: &mut AdaptedBase, index: usize, size: layout::Size) {
+        if index >= self.column_widths.len() {
+            self.column_widths.resize(index + 1, layout::Size::WrapContent);
+        }
+        self.column_widths[index] = size;
+        self.base.invalidate();
+    }
+    fn set_row_height(&mut self, _member: &mut MemberBase, _control: &mut ControlBase, _adapted: &mut AdaptedBase, index: usize, size: layout::Size) {
+        if index >= self.row_heights.len() {
+            self.row_heights.resize(index + 1, layout::Size::WrapContent);
+        }
+        self.row_heights[index] = size;
+        self.base.invalidate();
+    }
+}
+impl ItemClickableInner for TestableTable {
+    fn item_click(&mut self, i: &[usize], item_view: &mut dyn controls::Control, skip_callbacks: bool) {
+        if !skip_callbacks {
+            let id = self.base.id;
+            let self2 = self.base.as_outer_mut();
+            let mut callbacks = self.on_item_click.take();
+            for callback in callbacks.iter_mut() {
+                common::trace_callback(id, callback);
+                (callback.as_mut())(self2, i, item_view)
+            }
+            self.on_item_click.restore(callbacks);
+        }
+    }
+    fn on_item_click(&mut self, callback: Option<callbacks::OnItemClick>) {
+        self.on_item_click.set(callback);
+    }
+    fn add_on_item_click(&mut self, callback: callbacks::OnItemClick) -> callbacks::CallbackId {
+        self.on_item_click.add(callback)
+    }
+    fn remove_on_item_click(&mut self, id: callbacks::CallbackId) -> bool {
+        self.on_item_click.remove(id)
+    }
+}
+impl AdaptedInner for TestableTable {
+    fn on_item_change<'a>(&mut self, base: &mut MemberBase, value: adapter::Change<'a>) {
+        match value {
+            adapter::Change::Added(index, _) => {
+                plygui_api::trace::backend(base, format_args!("cell added {:?}", index));
+            },
+            adapter::Change::Removed(index) => {
+                plygui_api::trace::backend(base, format_args!("cell removed {:?}", index));
+            },
+            adapter::Change::Edited(index, _) => {
+                plygui_api::trace::backend(base, format_args!("cell edited {:?}", index));
+            }
+        }
+    }
+}
+impl Spawnable for TestableTable {
+    fn spawn() -> Box<dyn controls::Control> {
+        Self::with_adapter_initial_size(Box::new(types::imp::StringTableAdapter::<crate::imp::Text>::empty()), 0, 0).into_control()
+    }
+}
+impl ControlInner for TestableTable {
+    fn parent(&self) -> Option<&dyn controls::Member> {
+        self.base.parent().map(|p| p.as_member())
+    }
+    fn parent_mut(&mut self) -> Option<&mut dyn controls::Member> {
+        self.base.parent_mut().map(|p| p.as_member_mut())
+    }
+    fn root(&self) -> Option<&dyn controls::Member> {
+        self.base.root().map(|p| p.as_member())
+    }
+    fn root_mut(&mut self) -> Option<&mut dyn controls::Member> {
+        self.base.root_mut().map(|p| p.as_member_mut())
+    }
+    fn on_added_to_container(&mut self, member: &mut MemberBase, control: &mut ControlBase, parent: &dyn controls::Container, px: i32, py: i32, pw: u16, ph: u16) {
+        self.base.parent = Some(unsafe { parent.native_id() as InnerId });
+        self.base.position = (px, py);
+        control.coords = Some((px, py));
+
+        let (member, _, adapter, _) = Table::as_adapted_parts_mut(unsafe { utils::base_to_impl_mut(member) });
+
+        let mut indices: Vec<Vec<usize>> = Vec::new();
+        adapter.adapter.for_each(&mut |index: &[usize], node: &adapter::Node| {
+            if *node == adapter::Node::Leaf {
+                indices.push(index.to_vec());
+            }
+        });
+        let columns = cmp::max(1, self.column_widths.len()) as i32;
+        let cell_width = utils::coord_to_size(pw as i32 / columns);
+        let mut y = 0;
+        for index in indices {
+            let self2: &mut Table = unsafe { utils::base_to_impl_mut(member) };
+            if let Some(mut cell) = adapter.adapter.spawn_item_view(index.as_slice(), self2) {
+                let column = index.last().cloned().unwrap_or(0) as i32;
+                cell.on_added_to_container(self2, column * cell_width as i32, y, cell_width, ph);
+                if column + 1 >= columns {
+                    y += cell.size().1 as i32;
+                }
+                self.cells.push((index, cell));
+            }
+        }
+    }
+    fn on_removed_from_container(&mut self, member: &mut MemberBase, _control: &mut ControlBase, _: &dyn controls::Container) {
+        for (_, cell) in self.cells.iter_mut() {
+            let self2: &mut Table = unsafe { utils::base_to_impl_mut(member) };
+            cell.on_removed_from_container(self2);
+        }
+        self.cells.clear();
+        self.base.parent = None;
+    }
+    fn on_style_changed(&mut self, _member: &mut MemberBase, _control: &mut ControlBase, style: &style::Style) {
+        self.base.on_style_changed(style);
+    }
+
+    #[cfg(feature = "markup")]
+    fn fill_from_markup(&mut self, member: &mut MemberBase, _control: &mut ControlBase, markup: &plygui_api::markup::Markup, registry: &mut plygui_api::markup::MarkupRegistry) -> Result<(), plygui_api::Error> {
+        use plygui_api::markup::MEMBER_TYPE_TABLE;
+
+        fill_from_markup_base!(self, member, markup, registry, Table, [MEMBER_TYPE_TABLE]);
+        Ok(())
+    }
+}
+impl ContainerInner for TestableTable {
+    fn find_control_mut<'a>(&'a mut self, arg: types::FindBy<'a>) -> Option<&'a mut dyn controls::Control> {
+        for (_, cell) in self.cells.iter_mut() {
+            if arg.matches(cell.as_member()) {
+                return Some(cell.as_mut());
+            }
+            if let Some(c) = cell.is_container_mut() {
+                let ret = c.find_control_mut(arg);
+                if ret.is_none() {
+                    continue;
+                }
+                return ret;
+            }
+        }
+        None
+    }
+    fn find_control<'a>(&'a self, arg: types::FindBy<'a>) -> Option<&'a dyn controls::Control> {
+        for (_, cell) in self.cells.iter() {
+            if arg.matches(cell.as_member()) {
+                return Some(cell.as_ref());
+            }
+            if let Some(c) = cell.is_container() {
+                let ret = c.find_control(arg);
+                if ret.is_none() {
+                    continue;
+                }
+                return ret;
+            }
+        }
+        None
+    }
+}
+impl HasLayoutInner for TestableTable {
+    fn on_layout_changed(&mut self, _base: &mut MemberBase) {}
+}
+impl HasNativeIdInner for TestableTable {
+    type Id = common::TestableId;
+
+    fn native_id(&self) -> Self::Id {
+        self.base.id.into()
+    }
+}
+impl MemberInner for TestableTable {
+    fn on_accessibility_changed(&mut self, base: &mut MemberBase, name: Option<&str>, description: Option<&str>, role: a11y::Role) {
+        common::trace_accessibility(base, name, description, role);
+    }
+}
+
+impl HasSizeInner for TestableTable {
+    fn on_size_set(&mut self, base: &mut MemberBase, (width, height): (u16, u16)) -> bool {
+        use plygui_api::controls::HasLayout;
+
+        let this = base.as_any_mut().downcast_mut::<Table>().unwrap();
+        this.set_layout_width(layout::Size::Exact(width));
+        this.set_layout_height(layout::Size::Exact(height));
+        true
+    }
+}
+
+impl HasVisibilityInner for TestableTable {
+    fn on_visibility_set(&mut self, _base: &mut MemberBase, value: types::Visibility) -> bool {
+        self.base.on_set_visibility(value)
+    }
+}
+
+impl Drawable for TestableTable {
+    fn draw(&mut self, _member: &mut MemberBase, control: &mut ControlBase) {
+        self.base.draw("Table", control.coords, control.measured);
+    }
+    fn measure(&mut self, _member: &mut MemberBase, control: &mut ControlBase, parent_width: u16, parent_height: u16) -> (u16, u16, bool) {
+        let old_size = control.measured;
+        control.measured = match control.visibility {
+            types::Visibility::Gone => (0, 0),
+            _ => {
+                let w = match control.layout.width {
+                    layout::Size::MatchParent => parent_width,
+                    layout::Size::Exact(w) => w,
+                    layout::Size::WrapContent => defaults::THE_ULTIMATE_ANSWER_TO_EVERYTHING,
+                };
+                let h = match control.layout.height {
+                    layout::Size::MatchParent => parent_height,
+                    layout::Size::Exact(h) => h,
+                    layout::Size::WrapContent => defaults::THE_ULTIMATE_ANSWER_TO_EVERYTHING,
+                };
+                (w, h)
+            }
+        };
+        (control.measured.0, control.measured.1, control.measured != old_size)
+    }
+    fn invalidate(&mut self, _member: &mut MemberBase, _control: &mut ControlBase) {
+        self.base.relayout()
+    }
+}
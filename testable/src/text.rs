@@ -6,6 +6,8 @@ pub type Text = AMember<AControl<AText<TestableText>>>;
 pub struct TestableText {
     base: common::TestableControlBase<Text>,
     text: String,
+    font: types::Font,
+    color: types::Color,
 }
 
 impl HasLabelInner for TestableText {
@@ -17,29 +19,50 @@ impl HasLabelInner for TestableText {
         self.base.invalidate();
     }
 }
+impl HasFontInner for TestableText {
+    fn font(&self, _base: &MemberBase) -> Cow<'_, types::Font> {
+        Cow::Borrowed(&self.font)
+    }
+    fn set_font(&mut self, _base: &mut MemberBase, font: Cow<types::Font>) {
+        self.font = font.into_owned();
+        self.base.invalidate();
+    }
+}
+impl HasColorInner for TestableText {
+    fn color(&self, _base: &MemberBase) -> types::Color {
+        self.color
+    }
+    fn set_color(&mut self, _base: &mut MemberBase, color: types::Color) {
+        self.color = color;
+        common::trace_backend(self.base.id, format_args!("color {}", color));
+    }
+}
 impl<O: controls::Text> NewTextInner<O> for TestableText {
     fn with_uninit(u: &mut mem::MaybeUninit<O>) -> Self {
         TestableText {
             base: common::TestableControlBase::with_id(u),
             text: String::new(),
+            font: Default::default(),
+            color: types::Color::BLACK,
         }
     }
 }
 impl TextInner for TestableText {
     fn with_text<S: AsRef<str>>(text: S) -> Box<dyn controls::Text> {
         let mut b: Box<mem::MaybeUninit<Text>> = Box::new_uninit();
-        let mut ab = AMember::with_inner(
+        let ab = AMember::with_inner(
             AControl::with_inner(
                 AText::with_inner(
                     <Self as NewTextInner<Text>>::with_uninit(b.as_mut()),
                 ),
             )
         );
-        controls::HasLabel::set_label(&mut ab, text.as_ref().into());
-        unsafe {
+        let mut b = unsafe {
 	        b.as_mut_ptr().write(ab);
 	        b.assume_init()
-        }
+        };
+        controls::HasLabel::set_label(b.as_mut(), text.as_ref().into());
+        b
     }
 }
 impl Spawnable for TestableText {
@@ -55,6 +78,9 @@ impl ControlInner for TestableText {
     fn on_removed_from_container(&mut self, _member: &mut MemberBase, _control: &mut ControlBase, _: &dyn controls::Container) {
 	    self.base.parent = None;
     }
+    fn on_style_changed(&mut self, _member: &mut MemberBase, _control: &mut ControlBase, style: &style::Style) {
+        self.base.on_style_changed(style);
+    }
     fn parent(&self) -> Option<&dyn controls::Member> {
         self.base.parent().map(|p| p.as_member())
     }
@@ -69,17 +95,18 @@ impl ControlInner for TestableText {
     }
 
     #[cfg(feature = "markup")]
-    fn fill_from_markup(&mut self, member: &mut MemberBase, _control: &mut ControlBase, markup: &plygui_api::markup::Markup, registry: &mut plygui_api::markup::MarkupRegistry) {
+    fn fill_from_markup(&mut self, member: &mut MemberBase, _control: &mut ControlBase, markup: &plygui_api::markup::Markup, registry: &mut plygui_api::markup::MarkupRegistry) -> Result<(), plygui_api::Error> {
         use plygui_api::markup::MEMBER_TYPE_TEXT;
         fill_from_markup_base!(self, member, markup, registry, Text, [MEMBER_TYPE_TEXT]);
         fill_from_markup_label!(self, member, markup);
+        fill_from_markup_font!(self, member, markup);
+        fill_from_markup_color!(self, member, markup);
+        Ok(())
     }
 }
 
 impl HasLayoutInner for TestableText {
-    fn on_layout_changed(&mut self, _base: &mut MemberBase) {
-        self.base.invalidate();
-    }
+    fn on_layout_changed(&mut self, _base: &mut MemberBase) {}
 }
 
 impl HasSizeInner for TestableText {
@@ -89,7 +116,6 @@ impl HasSizeInner for TestableText {
         let this = base.as_any_mut().downcast_mut::<Text>().unwrap();
         this.set_layout_width(layout::Size::Exact(width));
         this.set_layout_width(layout::Size::Exact(height));
-        self.base.invalidate();
         
         unsafe { utils::base_to_impl_mut::<Text>(base) }.call_on_size::<Text>(width, height);
         
@@ -110,7 +136,11 @@ impl HasNativeIdInner for TestableText {
     }
 }
 
-impl MemberInner for TestableText {}
+impl MemberInner for TestableText {
+    fn on_accessibility_changed(&mut self, base: &mut MemberBase, name: Option<&str>, description: Option<&str>, role: a11y::Role) {
+        common::trace_accessibility(base, name, description, role);
+    }
+}
 
 impl Drawable for TestableText {
     fn draw(&mut self, _member: &mut MemberBase, control: &mut ControlBase) {
@@ -143,6 +173,6 @@ impl Drawable for TestableText {
         (control.measured.0, control.measured.1, control.measured != old_size)
     }
     fn invalidate(&mut self, _member: &mut MemberBase, _control: &mut ControlBase) {
-        self.base.invalidate()
+        self.base.relayout()
     }
 }
@@ -9,7 +9,7 @@ pub struct TestableTray {
 //    cfg: shellapi::NOTIFYICONDATAW,
 	menu: types::Menu,
 	image: image::DynamicImage,
-    on_close: Option<callbacks::OnClose>,
+    on_close: callbacks::CallbackList<callbacks::OnClose>,
 }
 
 pub type Tray = AMember<ACloseable<ATray<TestableTray>>>;
@@ -57,18 +57,31 @@ impl HasLabelInner for TestableTray {
 }
 
 impl CloseableInner for TestableTray {
+    /// Called by `Application::close_root`, which drops the tray once this returns `true`.
     fn close(&mut self, skip_callbacks: bool) -> bool {
-        use crate::plygui_api::controls::Member;
-        
-        let this = common::member_from_id::<Tray>(self.id).unwrap();
-        let id = this.id();
-        this.inner_mut().application_impl_mut::<crate::application::Application>().close_root(types::FindBy::Id(id), skip_callbacks);
-
-        println!("Tray '{}' closed ({:?})", self.label, self.id);
+        if !skip_callbacks {
+            let this = common::member_from_id::<Tray>(self.id).unwrap();
+            let mut callbacks = self.on_close.take();
+            let closing = callbacks.iter_mut().all(|callback| {
+                common::trace_callback(self.id, callback);
+                (callback.as_mut())(this)
+            });
+            self.on_close.restore(callbacks);
+            if !closing {
+                return false;
+            }
+        }
+        common::trace_backend(self.id, format_args!("Tray '{}' closed", self.label));
         true
     }
     fn on_close(&mut self, callback: Option<callbacks::OnClose>) {
-        self.on_close = callback;
+        self.on_close.set(callback);
+    }
+    fn add_on_close(&mut self, callback: callbacks::OnClose) -> callbacks::CallbackId {
+        self.on_close.add(callback)
+    }
+    fn remove_on_close(&mut self, id: callbacks::CallbackId) -> bool {
+        self.on_close.remove(id)
     }
     fn application<'a>(&'a self, base: &'a MemberBase) -> &'a dyn controls::Application {
         unsafe { utils::base_to_impl::<Tray>(base) }.inner().application_impl::<crate::application::Application>()
@@ -96,7 +109,7 @@ impl<O: controls::Tray> NewTrayInner<O> for TestableTray {
             label: title.to_owned(),
             menu: menu,
             image: icon,
-            on_close: None,
+            on_close: callbacks::CallbackList::new(),
         }
     }
 }
@@ -131,4 +144,8 @@ impl HasNativeIdInner for TestableTray {
     }
 }
 
-impl MemberInner for TestableTray {}
+impl MemberInner for TestableTray {
+    fn on_accessibility_changed(&mut self, base: &mut MemberBase, name: Option<&str>, description: Option<&str>, role: a11y::Role) {
+        common::trace_accessibility(base, name, description, role);
+    }
+}
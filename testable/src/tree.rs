@@ -0,0 +1,261 @@
+use crate::common::{self, *};
+
+pub type Tree = AMember<AControl<AContainer<AAdapted<ATree<TestableTree>>>>>;
+
+const INDENT: i32 = 8;
+
+#[repr(C)]
+pub struct TestableTree {
+    base: TestableControlBase<Tree>,
+    items: TreeNodeList<()>,
+    on_item_click: callbacks::CallbackList<callbacks::OnItemClick>,
+}
+impl<O: controls::Tree> NewTreeInner<O> for TestableTree {
+    fn with_uninit(u: &mut mem::MaybeUninit<O>) -> Self {
+        TestableTree {
+            base: common::TestableControlBase::with_id(u),
+            items: Default::default(),
+            on_item_click: callbacks::CallbackList::new(),
+        }
+    }
+}
+impl TreeInner for TestableTree {
+    fn with_adapter(adapter: Box<dyn types::Adapter>) -> Box<dyn controls::Tree> {
+        let mut b: Box<mem::MaybeUninit<Tree>> = Box::new_uninit();
+        let ab = AMember::with_inner(
+            AControl::with_inner(
+                AContainer::with_inner(
+                    AAdapted::with_inner(
+                        ATree::with_inner(
+                            <Self as NewTreeInner<Tree>>::with_uninit(b.as_mut())
+                        ),
+                        adapter,
+                        &mut b,
+                    ),
+                )
+            )
+        );
+        unsafe {
+            b.as_mut_ptr().write(ab);
+            b.assume_init()
+        }
+    }
+}
+impl TestableTree {
+    /// Adds the nodes in display order, indenting them by depth. Only expanded branches take space.
+    fn add_nodes(nodes: &mut [TreeNode<()>], parent: &mut Tree, depth: i32, y: &mut i32, visible: bool, pw: u16, ph: u16) {
+        for node in nodes.iter_mut() {
+            node.control.on_added_to_container(parent, depth * INDENT, *y, utils::coord_to_size(pw as i32 - depth * INDENT), ph);
+            if visible {
+                *y += node.control.size().1 as i32;
+            }
+            let expanded = visible && node.expanded;
+            Self::add_nodes(node.branches.as_mut_slice(), parent, depth + 1, y, expanded, pw, ph);
+        }
+    }
+    fn find_in<'a>(nodes: &'a [TreeNode<()>], arg: types::FindBy<'a>) -> Option<&'a dyn controls::Control> {
+        for node in nodes {
+            if arg.matches(node.control.as_member()) {
+                return Some(node.control.as_ref());
+            }
+            if let Some(found) = node.control.is_container().and_then(|c| c.find_control(arg)) {
+                return Some(found);
+            }
+            if let Some(found) = Self::find_in(node.branches.as_slice(), arg) {
+                return Some(found);
+            }
+        }
+        None
+    }
+    fn find_in_mut<'a>(nodes: &'a mut [TreeNode<()>], arg: types::FindBy<'a>) -> Option<&'a mut dyn controls::Control> {
+        for node in nodes {
+            if arg.matches(node.control.as_member()) {
+                return Some(node.control.as_mut());
+            }
+            if let Some(found) = node.control.is_container_mut().and_then(|c| c.find_control_mut(arg)) {
+                return Some(found);
+            }
+            if let Some(found) = Self::find_in_mut(node.branches.as_mut_slice(), arg) {
+                return Some(found);
+            }
+        }
+        None
+    }
+}
+impl ItemClickableInner for TestableTree {
+    fn item_click(&mut self, i: &[usize], item_view: &mut dyn controls::Control, skip_callbacks: bool) {
+        if !skip_callbacks {
+            let id = self.base.id;
+            let self2 = self.base.as_outer_mut();
+            let mut callbacks = self.on_item_click.take();
+            for callback in callbacks.iter_mut() {
+                common::trace_callback(id, callback);
+                (callback.as_mut())(self2, i, item_view)
+            }
+            self.on_item_click.restore(callbacks);
+        }
+    }
+    fn on_item_click(&mut self, callback: Option<callbacks::OnItemClick>) {
+        self.on_item_click.set(callback);
+    }
+    fn add_on_item_click(&mut self, callback: callbacks::OnItemClick) -> callbacks::CallbackId {
+        self.on_item_click.add(callback)
+    }
+    fn remove_on_item_click(&mut self, id: callbacks::CallbackId) -> bool {
+        self.on_item_click.remove(id)
+    }
+}
+impl AdaptedInner for TestableTree {
+    fn on_item_change<'a>(&mut self, base: &mut MemberBase, value: adapter::Change<'a>) {
+        match value {
+            adapter::Change::Added(index, _) => {
+                plygui_api::trace::backend(base, format_args!("item added {:?}", index));
+            },
+            adapter::Change::Removed(index) => {
+                plygui_api::trace::backend(base, format_args!("item removed {:?}", index));
+            },
+            adapter::Change::Edited(index, _) => {
+                plygui_api::trace::backend(base, format_args!("item edited {:?}", index));
+            }
+        }
+    }
+}
+impl Spawnable for TestableTree {
+    fn spawn() -> Box<dyn controls::Control> {
+        Self::with_adapter(Box::new(types::imp::StringVecAdapter::<crate::imp::Text>::new())).into_control()
+    }
+}
+impl ControlInner for TestableTree {
+    fn parent(&self) -> Option<&dyn controls::Member> {
+        self.base.parent().map(|p| p.as_member())
+    }
+    fn parent_mut(&mut self) -> Option<&mut dyn controls::Member> {
+        self.base.parent_mut().map(|p| p.as_member_mut())
+    }
+    fn root(&self) -> Option<&dyn controls::Member> {
+        self.base.root().map(|p| p.as_member())
+    }
+    fn root_mut(&mut self) -> Option<&mut dyn controls::Member> {
+        self.base.root_mut().map(|p| p.as_member_mut())
+    }
+    fn on_added_to_container(&mut self, member: &mut MemberBase, control: &mut ControlBase, parent: &dyn controls::Container, px: i32, py: i32, pw: u16, ph: u16) {
+        self.base.parent = Some(unsafe { parent.native_id() as InnerId });
+        self.base.position = (px, py);
+        control.coords = Some((px, py));
+
+        let (member, _, adapter, _) = Tree::as_adapted_parts_mut(unsafe { utils::base_to_impl_mut(member) });
+
+        // the adapter walks its nodes parents first, so every branch is there before its leaves
+        let mut indices: Vec<(Vec<usize>, adapter::Node)> = Vec::new();
+        adapter.adapter.for_each(&mut |index: &[usize], node: &adapter::Node| indices.push((index.to_vec(), *node)));
+        for (index, node) in indices {
+            let self2: &mut Tree = unsafe { utils::base_to_impl_mut(member) };
+            if let Some(item) = adapter.adapter.spawn_item_view(index.as_slice(), self2) {
+                let node = TreeNode {
+                    expanded: node == adapter::Node::Branch(true),
+                    control: item,
+                    native: (),
+                    branches: Vec::new(),
+                };
+                match index.split_last() {
+                    Some((_, [])) => self.items.0.push(node),
+                    Some((_, parent)) => self.items[parent].branches.push(node),
+                    None => {}
+                }
+            }
+        }
+        let mut y = 0;
+        Self::add_nodes(&mut self.items, unsafe { utils::base_to_impl_mut(member) }, 0, &mut y, true, pw, ph);
+    }
+    fn on_removed_from_container(&mut self, member: &mut MemberBase, _control: &mut ControlBase, _: &dyn controls::Container) {
+        fn remove(nodes: &mut [TreeNode<()>], parent: &mut Tree) {
+            for node in nodes.iter_mut() {
+                remove(node.branches.as_mut_slice(), parent);
+                node.control.on_removed_from_container(parent);
+            }
+        }
+        remove(&mut self.items, unsafe { utils::base_to_impl_mut(member) });
+        self.items.0.clear();
+        self.base.parent = None;
+    }
+    fn on_style_changed(&mut self, _member: &mut MemberBase, _control: &mut ControlBase, style: &style::Style) {
+        self.base.on_style_changed(style);
+    }
+
+    #[cfg(feature = "markup")]
+    fn fill_from_markup(&mut self, member: &mut MemberBase, _control: &mut ControlBase, markup: &plygui_api::markup::Markup, registry: &mut plygui_api::markup::MarkupRegistry) -> Result<(), plygui_api::Error> {
+        use plygui_api::markup::MEMBER_TYPE_TREE;
+
+        fill_from_markup_base!(self, member, markup, registry, Tree, [MEMBER_TYPE_TREE]);
+        Ok(())
+    }
+}
+impl ContainerInner for TestableTree {
+    fn find_control_mut<'a>(&'a mut self, arg: types::FindBy<'a>) -> Option<&'a mut dyn controls::Control> {
+        Self::find_in_mut(&mut self.items, arg)
+    }
+    fn find_control<'a>(&'a self, arg: types::FindBy<'a>) -> Option<&'a dyn controls::Control> {
+        Self::find_in(&self.items, arg)
+    }
+}
+impl HasLayoutInner for TestableTree {
+    fn on_layout_changed(&mut self, _base: &mut MemberBase) {}
+}
+impl HasNativeIdInner for TestableTree {
+    type Id = common::TestableId;
+
+    fn native_id(&self) -> Self::Id {
+        self.base.id.into()
+    }
+}
+impl MemberInner for TestableTree {
+    fn on_accessibility_changed(&mut self, base: &mut MemberBase, name: Option<&str>, description: Option<&str>, role: a11y::Role) {
+        common::trace_accessibility(base, name, description, role);
+    }
+}
+
+impl HasSizeInner for TestableTree {
+    fn on_size_set(&mut self, base: &mut MemberBase, (width, height): (u16, u16)) -> bool {
+        use plygui_api::controls::HasLayout;
+
+        let this = base.as_any_mut().downcast_mut::<Tree>().unwrap();
+        this.set_layout_width(layout::Size::Exact(width));
+        this.set_layout_height(layout::Size::Exact(height));
+        true
+    }
+}
+
+impl HasVisibilityInner for TestableTree {
+    fn on_visibility_set(&mut self, _base: &mut MemberBase, value: types::Visibility) -> bool {
+        self.base.on_set_visibility(value)
+    }
+}
+
+impl Drawable for TestableTree {
+    fn draw(&mut self, _member: &mut MemberBase, control: &mut ControlBase) {
+        self.base.draw("Tree", control.coords, control.measured);
+    }
+    fn measure(&mut self, _member: &mut MemberBase, control: &mut ControlBase, parent_width: u16, parent_height: u16) -> (u16, u16, bool) {
+        let old_size = control.measured;
+        control.measured = match control.visibility {
+            types::Visibility::Gone => (0, 0),
+            _ => {
+                let w = match control.layout.width {
+                    layout::Size::MatchParent => parent_width,
+                    layout::Size::Exact(w) => w,
+                    layout::Size::WrapContent => defaults::THE_ULTIMATE_ANSWER_TO_EVERYTHING,
+                };
+                let h = match control.layout.height {
+                    layout::Size::MatchParent => parent_height,
+                    layout::Size::Exact(h) => h,
+                    layout::Size::WrapContent => defaults::THE_ULTIMATE_ANSWER_TO_EVERYTHING,
+                };
+                (w, h)
+            }
+        };
+        (control.measured.0, control.measured.1, control.measured != old_size)
+    }
+    fn invalidate(&mut self, _member: &mut MemberBase, _control: &mut ControlBase) {
+        self.base.relayout()
+    }
+}
@@ -9,14 +9,15 @@ pub struct TestableWindow {
     position: (i32, i32),
     visibility: types::Visibility,
     menu: types::Menu,
-    on_close: Option<callbacks::OnClose>,
+    on_close: callbacks::CallbackList<callbacks::OnClose>,
 }
 
 pub type Window = AMember<AContainer<ASingleContainer<ACloseable<AWindow<TestableWindow>>>>>;
 
 impl TestableWindow {
 	pub fn draw(&mut self) {
-		println!("Window '{}' drawn ({} px, {} px) at {:?} ({:?})", self.label, self.size.0, self.size.1, self.position, self.id);
+		let scale = common::member_from_id::<Window>(self.id).map_or(1.0, |window| controls::Window::scale_factor(window));
+		common::trace_backend(self.id, format_args!("Window '{}' drawn ({} px, {} px) at {:?}, scale {}", self.label, self.size.0, self.size.1, self.position, scale));
 		if let Some(ref mut child) = self.child {
 			child.draw(Some((0, 0)));
 		}
@@ -60,7 +61,7 @@ impl<O: controls::Window> NewWindowInner<O> for TestableWindow {
 		    visibility: types::Visibility::Visible,
             child: None,
             menu: menu,
-            on_close: None,
+            on_close: callbacks::CallbackList::new(),
         }
     }
 }
@@ -95,10 +96,14 @@ impl WindowInner for TestableWindow {
     fn position(&self) -> (i32, i32) {
         self.position
     }
+    fn on_scale_factor_set(&mut self, _base: &mut MemberBase, old: f32, new: f32) {
+        let logical = |size: u16| (size as f32 * old / new).round() as u16;
+        self.size = (logical(self.size.0), logical(self.size.1));
+    }
 }
 
 impl ContainerInner for TestableWindow {
-    fn find_control_mut<'a>(&'a mut self, arg: &'a types::FindBy) -> Option<&'a mut dyn controls::Control> {
+    fn find_control_mut<'a>(&'a mut self, arg: types::FindBy<'a>) -> Option<&'a mut dyn controls::Control> {
         if let Some(child) = self.child.as_mut() {
             if let Some(c) = child.is_container_mut() {
                 return c.find_control_mut(arg);
@@ -106,7 +111,7 @@ impl ContainerInner for TestableWindow {
         }
         None
     }
-    fn find_control<'a>(&'a self, arg: &'a types::FindBy) -> Option<&'a dyn controls::Control> {
+    fn find_control<'a>(&'a self, arg: types::FindBy<'a>) -> Option<&'a dyn controls::Control> {
         if let Some(child) = self.child.as_ref() {
             if let Some(c) = child.is_container() {
                 return c.find_control(arg);
@@ -149,18 +154,31 @@ impl SingleContainerInner for TestableWindow {
 }
 
 impl CloseableInner for TestableWindow {
+    /// Called by `Application::close_root`, which drops the window once this returns `true`.
     fn close(&mut self, skip_callbacks: bool) -> bool {
-    	use crate::plygui_api::controls::Member;
-        
-        let this = common::member_from_id::<Window>(self.id).unwrap();
-        let id = this.id();
-        this.inner_mut().inner_mut().inner_mut().application_impl_mut::<crate::application::Application>().close_root(types::FindBy::Id(id), skip_callbacks);
-        
-        println!("Window '{}' closed ({:?})", self.label, self.id);
+        if !skip_callbacks {
+            let this = common::member_from_id::<Window>(self.id).unwrap();
+            let mut callbacks = self.on_close.take();
+            let closing = callbacks.iter_mut().all(|callback| {
+                common::trace_callback(self.id, callback);
+                (callback.as_mut())(this)
+            });
+            self.on_close.restore(callbacks);
+            if !closing {
+                return false;
+            }
+        }
+        common::trace_backend(self.id, format_args!("Window '{}' closed", self.label));
         true
     }
     fn on_close(&mut self, callback: Option<callbacks::OnClose>) {
-        self.on_close = callback;
+        self.on_close.set(callback);
+    }
+    fn add_on_close(&mut self, callback: callbacks::OnClose) -> callbacks::CallbackId {
+        self.on_close.add(callback)
+    }
+    fn remove_on_close(&mut self, id: callbacks::CallbackId) -> bool {
+        self.on_close.remove(id)
     }
     fn application<'a>(&'a self, base: &'a MemberBase) -> &'a dyn controls::Application {
         unsafe { utils::base_to_impl::<Window>(base) }.inner().inner().inner().application_impl::<crate::application::Application>()
@@ -176,7 +194,11 @@ impl HasNativeIdInner for TestableWindow {
         self.id.into()
     }
 }
-impl MemberInner for TestableWindow {}
+impl MemberInner for TestableWindow {
+    fn on_accessibility_changed(&mut self, base: &mut MemberBase, name: Option<&str>, description: Option<&str>, role: a11y::Role) {
+        common::trace_accessibility(base, name, description, role);
+    }
+}
 
 impl Drop for TestableWindow {
     fn drop(&mut self) {